@@ -1,7 +1,16 @@
+#[cfg(feature = "std")]
 use std::collections::HashMap as Map;
-use std::collections::hash_map::Iter;
+#[cfg(feature = "std")]
+use std::collections::hash_map::{Iter, IntoIter, Values, ValuesMut};
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap as Map;
+#[cfg(not(feature = "std"))]
+use alloc::collections::btree_map::{Iter, IntoIter, Values, ValuesMut};
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
 use crate::node::*;
 use crate::tree::*;
+use core::iter::FromIterator;
 
 //---- Structs ----//
 
@@ -22,6 +31,53 @@ impl<T: NodeContent> Forest<T> {
         }
     }
 
+    /// Create an empty forest with capacity for a number of trees.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `capacity` - Number of trees.
+    /// 
+    /// # Return
+    /// 
+    /// * Forest struct.
+    /// 
+    pub fn with_capacity(capacity: usize) -> Self {
+        // BTreeMap doesn't preallocate
+        #[cfg(not(feature = "std"))]
+        let _ = capacity;
+        Self {
+            #[cfg(feature = "std")]
+            trees: Map::with_capacity(capacity),
+            #[cfg(not(feature = "std"))]
+            trees: Map::new()
+        }
+    }
+
+    /// Get the number of trees the forest can hold without reallocating.
+    /// 
+    /// # Return
+    /// 
+    /// * Capacity. Without the `std` feature trees are stored in a BTreeMap, that doesn't preallocate, and the number of trees is returned.
+    ///
+    pub fn capacity(&self) -> usize {
+        #[cfg(feature = "std")]
+        return self.trees.capacity();
+        #[cfg(not(feature = "std"))]
+        return self.trees.len();
+    }
+
+    /// Shrink the capacity of the forest as much as possible.
+    /// 
+    /// # Return
+    /// 
+    /// * Nothing.
+    ///
+    pub fn shrink_to_fit(&mut self) {
+        // BTreeMap doesn't preallocate
+        #[cfg(feature = "std")]
+        self.trees.shrink_to_fit();
+    }
+
     /// Create new empty tree.
     /// 
     /// # Arguments
@@ -62,7 +118,7 @@ impl<T: NodeContent> Forest<T> {
     /// * An [`Option`] with the removed tree.
     /// 
     pub fn remove_tree(&mut self, name: &str) -> Option<Tree<T>> {
-        return self.trees.remove(name);
+        self.trees.remove(name)
     }
 
     /// Get tree reference.
@@ -103,13 +159,101 @@ impl<T: NodeContent> Forest<T> {
         }
     }
 
+    /// Move all the trees from another forest into this one.
+    /// 
+    /// If any tree name already exists in this forest, no tree is moved.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - Forest to merge.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with nothing, or the colliding tree name as error.
+    ///
+    pub fn merge(&mut self, other: Forest<T>) -> Result<(), String> {
+        // Check collisions before moving anything
+        if let Some(name) = other.trees.keys().find(|name| self.trees.contains_key(*name)) {
+            return Err(name.clone());
+        }
+        self.trees.extend(other.trees);
+        Ok(())
+    }
+
+    /// Convert all trees to another node content type, keeping their names.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with the new forest, or the name of the first tree that can't be converted as error.
+    ///
+    pub fn map_trees<U: NodeContent>(&self) -> Result<Forest<U>, String> {
+        let mut trees = Map::new();
+        for (name, tree) in self.trees.iter() {
+            trees.insert(name.clone(), tree.map::<U>().ok_or_else(|| name.clone())?);
+        }
+        Ok(Forest { trees })
+    }
+
     /// Get forest iterator.
     /// 
     /// # Return
     /// 
     /// * Iterator, provides a tuple with tree_name<[`String`]>, tree_struct<[`Tree`]>.
     /// 
-    pub fn iter(&self) -> Iter<String, Tree<T>> {
+    pub fn iter(&self) -> Iter<'_, String, Tree<T>> {
         self.trees.iter()
     }
+
+    /// Get iterator over the trees, without their names.
+    /// 
+    /// # Return
+    /// 
+    /// * Iterator, provides tree references.
+    ///
+    pub fn values(&self) -> Values<'_, String, Tree<T>> {
+        self.trees.values()
+    }
+
+    /// Get mutable iterator over the trees, without their names.
+    /// 
+    /// # Return
+    /// 
+    /// * Iterator, provides mutable tree references.
+    ///
+    pub fn values_mut(&mut self) -> ValuesMut<'_, String, Tree<T>> {
+        self.trees.values_mut()
+    }
+}
+
+/// Create an empty forest, like [`Forest::new()`].
+impl<T: NodeContent> Default for Forest<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: NodeContent> IntoIterator for Forest<T> {
+    type Item = (String, Tree<T>);
+    type IntoIter = IntoIter<String, Tree<T>>;
+
+    /// Consume the forest and get an iterator over its trees.
+    fn into_iter(self) -> Self::IntoIter {
+        self.trees.into_iter()
+    }
+}
+
+/// Build a forest from (name, tree) pairs. If a name is repeated, the last tree wins.
+impl<T: NodeContent> FromIterator<(String, Tree<T>)> for Forest<T> {
+    fn from_iter<I: IntoIterator<Item = (String, Tree<T>)>>(iter: I) -> Self {
+        Self {
+            trees: iter.into_iter().collect()
+        }
+    }
+}
+
+/// Build a forest from (name, tree) pairs. If a name is repeated, the last tree wins.
+impl<'a, T: NodeContent> FromIterator<(&'a str, Tree<T>)> for Forest<T> {
+    fn from_iter<I: IntoIterator<Item = (&'a str, Tree<T>)>>(iter: I) -> Self {
+        iter.into_iter().map(|(name, tree)| (String::from(name), tree)).collect()
+    }
 }
\ No newline at end of file
@@ -1,9 +1,35 @@
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
 use crate::tree::*;
 use crate::node::*;
 
+/// Traversal order, used by [`IterInterface::traverse()`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TraversalOrder {
+    /// BFS, see [`IterInterface::bfs()`].
+    Bfs,
+    /// Inverse BFS, see [`IterInterface::inv_bfs()`].
+    InvBfs,
+    /// Pre-Order DFS, see [`IterInterface::pre_dfs()`].
+    PreDfs,
+    /// Inverse Pre-Order DFS, see [`IterInterface::inv_pre_dfs()`].
+    InvPreDfs,
+    /// Post-Order DFS, see [`IterInterface::post_dfs()`].
+    PostDfs,
+    /// Inverse Post-Order DFS, see [`IterInterface::inv_post_dfs()`].
+    InvPostDfs,
+    /// In-Order DFS, see [`IterInterface::in_dfs()`].
+    InDfs,
+    /// Sequential, see [`IterInterface::sequential()`].
+    Sequential,
+    /// Inverse sequential, see [`IterInterface::inv_sequential()`].
+    InvSequential
+}
+
 /// Interface for tree iterators.
 pub struct IterInterface<'a, T: NodeContent> {
-    tree: &'a Tree<T>
+    tree: &'a Tree<T>,
+    initial_node: usize
 }
 
 impl<'a, T: NodeContent> IterInterface<'a, T> {
@@ -18,12 +44,27 @@ impl<'a, T: NodeContent> IterInterface<'a, T> {
     /// * Iterator interface.
     ///
     pub fn new(tree: &'a Tree<T>) -> Self {
-        IterInterface { tree }
+        IterInterface { tree, initial_node: 0 }
+    }
+
+    /// Create iterator interface, starting at a certain node.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tree` - Reference to tree.
+    /// * `initial_node` - Index of the node where traversals start.
+    /// 
+    /// # Return
+    /// 
+    /// * Iterator interface.
+    ///
+    pub fn new_at(tree: &'a Tree<T>, initial_node: usize) -> Self {
+        IterInterface { tree, initial_node }
     }
 
     /// Get sequential iterator.
     /// 
-    /// Iterates over the array that contains the nodes in sequential order. Even unlinked nodes.
+    /// Iterates over the array that contains the nodes in sequential order. Even unlinked nodes, and regardless of the initial node.
     /// 
     /// # Return
     /// 
@@ -35,7 +76,7 @@ impl<'a, T: NodeContent> IterInterface<'a, T> {
 
     /// Get inverse sequential iterator.
     /// 
-    /// Iterates over the array that contains the nodes in inverted sequential order. Even unlinked nodes.
+    /// Iterates over the array that contains the nodes in inverted sequential order. Even unlinked nodes, and regardless of the initial node.
     /// 
     /// # Return
     /// 
@@ -52,7 +93,7 @@ impl<'a, T: NodeContent> IterInterface<'a, T> {
     /// * Iterator.
     ///
     pub fn bfs(&self) -> BfsIter<'a, T> {
-        BfsIter::new(self.tree)
+        BfsIter::new_at(self.tree, self.initial_node)
     }
 
     /// Get Inverse BFS iterator.
@@ -62,7 +103,7 @@ impl<'a, T: NodeContent> IterInterface<'a, T> {
     /// * Iterator.
     ///
     pub fn inv_bfs(&self) -> InvBfsIter<'a, T> {
-        InvBfsIter::new(self.tree)
+        InvBfsIter::new_at(self.tree, self.initial_node)
     }
 
     /// Get Pre-Order DFS iterator
@@ -72,7 +113,7 @@ impl<'a, T: NodeContent> IterInterface<'a, T> {
     /// * Iterator.
     ///
     pub fn pre_dfs(&self) -> PreDfsIter<'a, T> {
-        PreDfsIter::new(self.tree)
+        PreDfsIter::new_at(self.tree, self.initial_node)
     }
 
     /// Get Inverse Pre-Order DFS iterator
@@ -82,7 +123,7 @@ impl<'a, T: NodeContent> IterInterface<'a, T> {
     /// * Iterator.
     ///
     pub fn inv_pre_dfs(&self) -> InvPreDfsIter<'a, T> {
-        InvPreDfsIter::new(self.tree)
+        InvPreDfsIter::new_at(self.tree, self.initial_node)
     }
 
     /// Get Post-Order DFS iterator
@@ -92,7 +133,7 @@ impl<'a, T: NodeContent> IterInterface<'a, T> {
     /// * Iterator.
     ///
     pub fn post_dfs(&self) -> PostDfsIter<'a, T> {
-        PostDfsIter::new(self.tree)
+        PostDfsIter::new_at(self.tree, self.initial_node)
     }
 
     /// Get Inverse Post-Order DFS iterator
@@ -102,21 +143,121 @@ impl<'a, T: NodeContent> IterInterface<'a, T> {
     /// * Iterator.
     ///
     pub fn inv_post_dfs(&self) -> InvPostDfsIter<'a, T> {
-        InvPostDfsIter::new(self.tree)
+        InvPostDfsIter::new_at(self.tree, self.initial_node)
+    }
+
+    /// Get In-Order DFS iterator
+    /// 
+    /// Every node is visited once, after the subtree of its first child and before the subtrees of the rest of its children. A node without children is visited directly.
+    /// 
+    /// # Return
+    /// 
+    /// * Iterator.
+    ///
+    pub fn in_dfs(&self) -> InDfsIter<'a, T> {
+        InDfsIter::new_at(self.tree, self.initial_node)
+    }
+
+    /// Get an iterator with the order selected at runtime.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `order` - Traversal order.
+    /// 
+    /// # Return
+    /// 
+    /// * Boxed iterator.
+    ///
+    pub fn traverse(&self, order: TraversalOrder) -> Box<dyn Iterator<Item = (&'a Node<T>, usize)> + 'a> {
+        match order {
+            TraversalOrder::Bfs => Box::new(self.bfs()),
+            TraversalOrder::InvBfs => Box::new(self.inv_bfs()),
+            TraversalOrder::PreDfs => Box::new(self.pre_dfs()),
+            TraversalOrder::InvPreDfs => Box::new(self.inv_pre_dfs()),
+            TraversalOrder::PostDfs => Box::new(self.post_dfs()),
+            TraversalOrder::InvPostDfs => Box::new(self.inv_post_dfs()),
+            TraversalOrder::InDfs => Box::new(self.in_dfs()),
+            TraversalOrder::Sequential => Box::new(self.sequential()),
+            TraversalOrder::InvSequential => Box::new(self.inv_sequential())
+        }
+    }
+
+    /// Get Post-Order DFS iterator with subtree sizes.
+    /// 
+    /// Along with the node and its index, provides the number of nodes in its subtree, including itself.
+    /// 
+    /// # Return
+    /// 
+    /// * Iterator.
+    ///
+    pub fn post_dfs_with_size(&self) -> PostDfsSizeIter<'a, T> {
+        PostDfsSizeIter::new_at(self.tree, self.initial_node)
+    }
+
+    /// Get Pre-Order DFS paths iterator.
+    /// 
+    /// Iterates in Pre-Order DFS, providing the path of keys of each node, starting from the initial node.
+    /// 
+    /// # Return
+    /// 
+    /// * Iterator.
+    ///
+    pub fn preorder_paths(&self) -> PreorderPathsIter<'a, T> {
+        PreorderPathsIter::new_at(self.tree, self.initial_node)
+    }
+
+    /// Get children iterator.
+    /// 
+    /// Iterates over the children of the initial node, skipping unlinked ones.
+    /// 
+    /// # Return
+    /// 
+    /// * Iterator.
+    ///
+    pub fn children(&self) -> ChildrenIter<'a, T> {
+        ChildrenIter::new(self.tree, self.initial_node)
+    }
+
+    /// Get level iterator.
+    /// 
+    /// Iterates over the levels of the tree, providing the level number and all the nodes at that level in BFS order.
+    /// 
+    /// # Return
+    /// 
+    /// * Iterator.
+    ///
+    pub fn by_level(&self) -> LevelIter<'a, T> {
+        LevelIter::new_at(self.tree, self.initial_node)
+    }
+
+    /// Get DFS events iterator.
+    /// 
+    /// Iterates in Pre-Order DFS, generating a [`DfsEvent::Enter`] when a node is reached and a [`DfsEvent::Leave`] after all its children.
+    /// 
+    /// # Return
+    /// 
+    /// * Iterator.
+    ///
+    pub fn dfs_events(&self) -> DfsEventsIter<'a, T> {
+        DfsEventsIter::new_at(self.tree, self.initial_node)
     }
 }
 
 /// Simple Iterator, in sequential order.
+/// 
+/// It's double ended, so it can be reversed.
 pub struct SequentialIter<'a, T: NodeContent> {
     tree: &'a Tree<T>,
-    position: usize
+    position: usize,
+    end: usize
 }
 
 impl<'a, T: NodeContent> SequentialIter<'a, T> {
     pub fn new(tree: &'a Tree<T>) -> Self {
         Self {
             tree,
-            position: 0
+            position: 0,
+            end: tree.get_nodes_len()
         }
     }
 }
@@ -124,6 +265,9 @@ impl<'a, T: NodeContent> SequentialIter<'a, T> {
 impl<'a, T: NodeContent> Iterator for SequentialIter<'a, T> {
     type Item = (&'a Node<T>, usize);
     fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.end {
+            return None;
+        }
         let position = self.position;
         match &self.tree.get_nodes_ref().get(self.position) {
             Some(node) => {
@@ -133,6 +277,26 @@ impl<'a, T: NodeContent> Iterator for SequentialIter<'a, T> {
             None => None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end.saturating_sub(self.position);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T: NodeContent> DoubleEndedIterator for SequentialIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.position >= self.end {
+            return None;
+        }
+        match &self.tree.get_nodes_ref().get(self.end - 1) {
+            Some(node) => {
+                self.end -= 1;
+                Some((node, self.end))
+            },
+            None => None
+        }
+    }
 }
 
 /// Simple Iterator, in inverted sequential order.
@@ -161,7 +325,7 @@ impl<'a, T: NodeContent> InvSequentialIter<'a, T> {
     }
 }
 
-impl<'a, 'b, T: NodeContent> Iterator for InvSequentialIter<'a, T> {
+impl<'a, T: NodeContent> Iterator for InvSequentialIter<'a, T> {
     type Item = (&'a Node<T>, usize);
     fn next(&mut self) -> Option<Self::Item> {
         if self.finished {
@@ -181,6 +345,11 @@ impl<'a, 'b, T: NodeContent> Iterator for InvSequentialIter<'a, T> {
             None => None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.finished { 0 } else { self.position + 1 };
+        (remaining, Some(remaining))
+    }
 }
 
 /// BFS Iterator.
@@ -194,13 +363,27 @@ pub struct BfsIter<'a, T: NodeContent> {
 
 impl<'a, T: NodeContent> BfsIter<'a, T> {
     pub fn new(tree: &'a Tree<T>) -> Self {
+        Self::new_at(tree, 0)
+    }
+
+    pub fn new_at(tree: &'a Tree<T>, initial_node: usize) -> Self {
         Self {
             tree,
             cua: vec!(),
-            next: 0,
+            next: initial_node,
             finished: false
         }
     }
+
+    /// Get number of nodes pending to be visited, the ones in the queue plus the next one.
+    /// 
+    /// # Return
+    /// 
+    /// * Number of nodes.
+    ///
+    pub fn pending(&self) -> usize {
+        if self.finished { 0 } else { self.cua.len() + 1 }
+    }
 }
 
 impl<'a, T: NodeContent> Iterator for BfsIter<'a, T> {
@@ -212,12 +395,12 @@ impl<'a, T: NodeContent> Iterator for BfsIter<'a, T> {
         // Get current node
         let position = self.next;
         if let Some(node) = self.tree.get_nodes_ref().get(position) {
-            // Put in the queue all children of current node
-            for child in node.get_children_ref().iter() {
+            // Put in the queue all children of current node, skipping unlinked ones
+            for child in node.get_children_ref().iter().filter(|child| **child != usize::MAX) {
                 self.cua.push(*child);
             }
             // Get next node from queue.
-            if self.cua.len() > 0 {
+            if !self.cua.is_empty() {
                 self.next = self.cua.remove(0);
             }
             else {
@@ -232,6 +415,10 @@ impl<'a, T: NodeContent> Iterator for BfsIter<'a, T> {
         }
 
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished { (0, Some(0)) } else { (self.tree.get_nodes_ref().get(self.next).is_some() as usize, None) }
+    }
 }
 
 /// Inverse BFS Iterator.
@@ -245,13 +432,27 @@ pub struct InvBfsIter<'a, T: NodeContent> {
 
 impl<'a, T: NodeContent> InvBfsIter<'a, T> {
     pub fn new(tree: &'a Tree<T>) -> Self {
+        Self::new_at(tree, 0)
+    }
+
+    pub fn new_at(tree: &'a Tree<T>, initial_node: usize) -> Self {
         Self {
             tree,
             cua: vec!(),
-            next: 0,
+            next: initial_node,
             finished: false
         }
     }
+
+    /// Get number of nodes pending to be visited, the ones in the queue plus the next one.
+    /// 
+    /// # Return
+    /// 
+    /// * Number of nodes.
+    ///
+    pub fn pending(&self) -> usize {
+        if self.finished { 0 } else { self.cua.len() + 1 }
+    }
 }
 
 impl<'a, T: NodeContent> Iterator for InvBfsIter<'a, T> {
@@ -263,12 +464,12 @@ impl<'a, T: NodeContent> Iterator for InvBfsIter<'a, T> {
         // Get current node
         let position = self.next;
         if let Some(node) = self.tree.get_nodes_ref().get(position) {
-            // Put in the queue all children of current node
-            for child in node.get_children_ref().iter().rev() {
+            // Put in the queue all children of current node, skipping unlinked ones
+            for child in node.get_children_ref().iter().rev().filter(|child| **child != usize::MAX) {
                 self.cua.push(*child);
             }
             // Get next node from queue.
-            if self.cua.len() > 0 {
+            if !self.cua.is_empty() {
                 self.next = self.cua.remove(0);
             }
             else {
@@ -283,6 +484,10 @@ impl<'a, T: NodeContent> Iterator for InvBfsIter<'a, T> {
         }
 
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished { (0, Some(0)) } else { (self.tree.get_nodes_ref().get(self.next).is_some() as usize, None) }
+    }
 }
 
 /// Pre-Order DFS Iterator
@@ -295,13 +500,27 @@ pub struct PreDfsIter<'a, T: NodeContent> {
 
 impl<'a, T: NodeContent> PreDfsIter<'a, T> {
     pub fn new(tree: &'a Tree<T>) -> Self {
+        Self::new_at(tree, 0)
+    }
+
+    pub fn new_at(tree: &'a Tree<T>, initial_node: usize) -> Self {
         Self {
             tree,
             pila: vec!(),
-            next: 0,
+            next: initial_node,
             finished: false
         }
     }
+
+    /// Get number of nodes pending to be visited, the ones in the stack plus the next one.
+    /// 
+    /// # Return
+    /// 
+    /// * Number of nodes.
+    ///
+    pub fn pending(&self) -> usize {
+        if self.finished { 0 } else { self.pila.len() + 1 }
+    }
 }
 
 impl<'a, T: NodeContent> Iterator for PreDfsIter<'a, T> {
@@ -313,8 +532,8 @@ impl<'a, T: NodeContent> Iterator for PreDfsIter<'a, T> {
         // Get current node
         let position = self.next;
         if let Some(node) = self.tree.get_nodes_ref().get(position) {
-            // Put in the stack all children of current node
-            for child in node.get_children_ref().iter().rev() {
+            // Put in the stack all children of current node, skipping unlinked ones
+            for child in node.get_children_ref().iter().rev().filter(|child| **child != usize::MAX) {
                 self.pila.push(*child);
             }
             // Get next node from stack.
@@ -333,6 +552,10 @@ impl<'a, T: NodeContent> Iterator for PreDfsIter<'a, T> {
         }
 
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished { (0, Some(0)) } else { (self.tree.get_nodes_ref().get(self.next).is_some() as usize, None) }
+    }
 }
 
 /// Inverse Pre-Order DFS Iterator.
@@ -345,13 +568,27 @@ pub struct InvPreDfsIter<'a, T: NodeContent> {
 
 impl<'a, T: NodeContent> InvPreDfsIter<'a, T> {
     pub fn new(tree: &'a Tree<T>) -> Self {
+        Self::new_at(tree, 0)
+    }
+
+    pub fn new_at(tree: &'a Tree<T>, initial_node: usize) -> Self {
         Self {
             tree,
             pila: vec!(),
-            next: 0,
+            next: initial_node,
             finished: false
         }
     }
+
+    /// Get number of nodes pending to be visited, the ones in the stack plus the next one.
+    /// 
+    /// # Return
+    /// 
+    /// * Number of nodes.
+    ///
+    pub fn pending(&self) -> usize {
+        if self.finished { 0 } else { self.pila.len() + 1 }
+    }
 }
 
 impl<'a, T: NodeContent> Iterator for InvPreDfsIter<'a, T> {
@@ -363,8 +600,8 @@ impl<'a, T: NodeContent> Iterator for InvPreDfsIter<'a, T> {
         // Get current node
         let position = self.next;
         if let Some(node) = self.tree.get_nodes_ref().get(position) {
-            // Put in the stack all children of current node
-            for child in node.get_children_ref().iter() {
+            // Put in the stack all children of current node, skipping unlinked ones
+            for child in node.get_children_ref().iter().filter(|child| **child != usize::MAX) {
                 self.pila.push(*child);
             }
             // Get next node from stack.
@@ -383,6 +620,10 @@ impl<'a, T: NodeContent> Iterator for InvPreDfsIter<'a, T> {
         }
 
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished { (0, Some(0)) } else { (self.tree.get_nodes_ref().get(self.next).is_some() as usize, None) }
+    }
 }
 
 /// Post-Order DFS Iterator.
@@ -393,11 +634,37 @@ pub struct PostDfsIter<'a, T: NodeContent> {
 
 impl<'a, T: NodeContent> PostDfsIter<'a, T> {
     pub fn new(tree: &'a Tree<T>) -> Self {
+        Self::new_at(tree, 0)
+    }
+
+    pub fn new_at(tree: &'a Tree<T>, initial_node: usize) -> Self {
         Self {
             tree,
-            pila: vec!((0, true))
+            pila: vec!((initial_node, true))
         }
     }
+
+    /// Get number of nodes pending to be visited, the ones in the stack.
+    /// 
+    /// # Return
+    /// 
+    /// * Number of nodes.
+    ///
+    pub fn pending(&self) -> usize {
+        self.pila.len()
+    }
+
+    /// Check if the next node taken from the stack is a revisit, a node whose children have already been returned.
+    /// 
+    /// It doesn't advance the iterator. A revisited node is always the next one returned.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with true if the next node is a revisit, or None if the stack is empty.
+    ///
+    pub fn peek_is_revisit(&self) -> Option<bool> {
+        self.pila.last().map(|(_, push_children)| !push_children)
+    }
 }
 
 impl<'a, T: NodeContent> Iterator for PostDfsIter<'a, T> {
@@ -416,9 +683,9 @@ impl<'a, T: NodeContent> Iterator for PostDfsIter<'a, T> {
                     return Some((node, position));
                 }
                 // it has children, put in stack
-                if node.get_children_ref().len() > 0 {
+                if !node.get_children_ref().is_empty() {
                     self.pila.push((next, false));
-                    for child in node.get_children_ref().iter().rev() {
+                    for child in node.get_children_ref().iter().rev().filter(|child| **child != usize::MAX) {
                         self.pila.push((*child, true));
                     }
                     // Keep trying until we find a node we can return
@@ -436,6 +703,15 @@ impl<'a, T: NodeContent> Iterator for PostDfsIter<'a, T> {
         }
         None
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.pila.last() {
+            // Next node to return is known
+            Some((position, false)) => (self.tree.get_nodes_ref().get(*position).is_some() as usize, None),
+            Some(_) => (0, None),
+            None => (0, Some(0))
+        }
+    }
 }
 
 /// Inverse Post-Order Iterator.
@@ -446,11 +722,37 @@ pub struct InvPostDfsIter<'a, T: NodeContent> {
 
 impl<'a, T: NodeContent> InvPostDfsIter<'a, T> {
     pub fn new(tree: &'a Tree<T>) -> Self {
+        Self::new_at(tree, 0)
+    }
+
+    pub fn new_at(tree: &'a Tree<T>, initial_node: usize) -> Self {
         Self {
             tree,
-            pila: vec!((0, true))
+            pila: vec!((initial_node, true))
         }
     }
+
+    /// Get number of nodes pending to be visited, the ones in the stack.
+    /// 
+    /// # Return
+    /// 
+    /// * Number of nodes.
+    ///
+    pub fn pending(&self) -> usize {
+        self.pila.len()
+    }
+
+    /// Check if the next node taken from the stack is a revisit, a node whose children have already been returned.
+    /// 
+    /// It doesn't advance the iterator. A revisited node is always the next one returned.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with true if the next node is a revisit, or None if the stack is empty.
+    ///
+    pub fn peek_is_revisit(&self) -> Option<bool> {
+        self.pila.last().map(|(_, push_children)| !push_children)
+    }
 }
 
 impl<'a, T: NodeContent> Iterator for InvPostDfsIter<'a, T> {
@@ -468,9 +770,9 @@ impl<'a, T: NodeContent> Iterator for InvPostDfsIter<'a, T> {
                     return Some((node, position));
                 }
                 // it has children, put in stack
-                if node.get_children_ref().len() > 0 {
+                if !node.get_children_ref().is_empty() {
                     self.pila.push((next, false));
-                    for child in node.get_children_ref().iter() {
+                    for child in node.get_children_ref().iter().filter(|child| **child != usize::MAX) {
                         self.pila.push((*child, true));
                     }
                     // Keep trying until we find a node we can return
@@ -488,4 +790,331 @@ impl<'a, T: NodeContent> Iterator for InvPostDfsIter<'a, T> {
         }
         None
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.pila.last() {
+            // Next node to return is known
+            Some((position, false)) => (self.tree.get_nodes_ref().get(*position).is_some() as usize, None),
+            Some(_) => (0, None),
+            None => (0, Some(0))
+        }
+    }
+}
+
+/// In-Order DFS Iterator.
+pub struct InDfsIter<'a, T: NodeContent> {
+    tree: &'a Tree<T>,
+    pila: Vec<(usize, bool)>
+}
+
+impl<'a, T: NodeContent> InDfsIter<'a, T> {
+    pub fn new(tree: &'a Tree<T>) -> Self {
+        Self::new_at(tree, 0)
+    }
+
+    pub fn new_at(tree: &'a Tree<T>, initial_node: usize) -> Self {
+        Self {
+            tree,
+            pila: if tree.get_nodes_len() > initial_node { vec!((initial_node, false)) } else { vec!() }
+        }
+    }
+
+    /// Get number of nodes pending to be visited, the ones in the stack.
+    /// 
+    /// # Return
+    /// 
+    /// * Number of nodes.
+    ///
+    pub fn pending(&self) -> usize {
+        self.pila.len()
+    }
+}
+
+impl<'a, T: NodeContent> Iterator for InDfsIter<'a, T> {
+    type Item = (&'a Node<T>, usize);
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((position, visit)) = self.pila.pop() {
+            let node = self.tree.get_nodes_ref().get(position)?;
+            if visit {
+                // First child subtree already visited
+                return Some((node, position));
+            }
+            let linked_children: Vec<usize> = node.get_children_ref().iter().copied().filter(|child| *child < self.tree.get_nodes_len()).collect();
+            if let Some((first_child, other_children)) = linked_children.split_first() {
+                // Put in the stack the rest of children, then the node itself, and then the first child
+                for child in other_children.iter().rev() {
+                    self.pila.push((*child, false));
+                }
+                self.pila.push((position, true));
+                self.pila.push((*first_child, false));
+            }
+            else {
+                // No children, a leaf
+                return Some((node, position));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every node in the stack generates at least one node
+        (self.pila.len(), None)
+    }
+}
+
+/// Children Iterator.
+pub struct ChildrenIter<'a, T: NodeContent> {
+    tree: &'a Tree<T>,
+    initial_node: usize,
+    position: usize,
+    finished: bool
+}
+
+impl<'a, T: NodeContent> ChildrenIter<'a, T> {
+    pub fn new(tree: &'a Tree<T>, initial_node: usize) -> Self {
+        Self {
+            tree,
+            initial_node,
+            position: 0,
+            // Nothing to iterate if the initial node doesn't exist or is unlinked
+            finished: !tree.is_linked(initial_node)
+        }
+    }
+}
+
+impl<'a, T: NodeContent> Iterator for ChildrenIter<'a, T> {
+    type Item = (&'a Node<T>, usize);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        if let Some(initial_node) = self.tree.get_nodes_ref().get(self.initial_node) {
+            while let Some(child) = initial_node.get_children_ref().get(self.position) {
+                self.position += 1;
+                // Skip unlinked children
+                if let Some(node) = self.tree.get_nodes_ref().get(*child) {
+                    return Some((node, *child));
+                }
+            }
+        }
+        self.finished = true;
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            return (0, Some(0));
+        }
+        match self.tree.get_nodes_ref().get(self.initial_node) {
+            Some(node) => (0, Some(node.get_num_children().saturating_sub(self.position))),
+            None => (0, Some(0))
+        }
+    }
+}
+
+/// Level Iterator.
+pub struct LevelIter<'a, T: NodeContent> {
+    tree: &'a Tree<T>,
+    current_level: Vec<usize>
+}
+
+impl<'a, T: NodeContent> LevelIter<'a, T> {
+    pub fn new(tree: &'a Tree<T>) -> Self {
+        Self::new_at(tree, 0)
+    }
+
+    pub fn new_at(tree: &'a Tree<T>, initial_node: usize) -> Self {
+        Self {
+            tree,
+            current_level: if tree.get_nodes_len() > initial_node { vec!(initial_node) } else { vec!() }
+        }
+    }
+}
+
+impl<'a, T: NodeContent> Iterator for LevelIter<'a, T> {
+    type Item = (usize, Vec<(&'a Node<T>, usize)>);
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut nodes = vec!();
+        let mut next_level = vec!();
+        for node_index in self.current_level.iter() {
+            if let Some(node) = self.tree.get_nodes_ref().get(*node_index) {
+                nodes.push((node, *node_index));
+                // Put in the next level all linked children of current node
+                for child in node.get_children_ref().iter() {
+                    if *child < self.tree.get_nodes_len() {
+                        next_level.push(*child);
+                    }
+                }
+            }
+        }
+        self.current_level = next_level;
+        if let Some((node, _)) = nodes.first() {
+            Some((node.get_level(), nodes))
+        }
+        else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.current_level.is_empty() { (0, Some(0)) } else { (1, None) }
+    }
+}
+
+/// Event generated by [`DfsEventsIter`].
+#[derive(Debug)]
+pub enum DfsEvent<'a, T: NodeContent> {
+    /// Node reached, before its children.
+    Enter(&'a Node<T>, usize),
+    /// Node left, after its children.
+    Leave(&'a Node<T>, usize)
+}
+
+/// DFS Events Iterator.
+pub struct DfsEventsIter<'a, T: NodeContent> {
+    tree: &'a Tree<T>,
+    pila: Vec<(usize, bool)>
+}
+
+impl<'a, T: NodeContent> DfsEventsIter<'a, T> {
+    pub fn new(tree: &'a Tree<T>) -> Self {
+        Self::new_at(tree, 0)
+    }
+
+    pub fn new_at(tree: &'a Tree<T>, initial_node: usize) -> Self {
+        Self {
+            tree,
+            pila: if tree.get_nodes_len() > initial_node { vec!((initial_node, false)) } else { vec!() }
+        }
+    }
+}
+
+impl<'a, T: NodeContent> Iterator for DfsEventsIter<'a, T> {
+    type Item = DfsEvent<'a, T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (position, entered) = self.pila.pop()?;
+        let node = self.tree.get_nodes_ref().get(position)?;
+        if entered {
+            return Some(DfsEvent::Leave(node, position));
+        }
+        // Leave after all children
+        self.pila.push((position, true));
+        // Put in the stack all linked children of current node
+        for child in node.get_children_ref().iter().rev() {
+            if *child < self.tree.get_nodes_len() {
+                self.pila.push((*child, false));
+            }
+        }
+        Some(DfsEvent::Enter(node, position))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every node in the stack generates at least one event
+        (self.pila.len(), None)
+    }
+}
+
+/// Post-Order DFS Iterator with subtree sizes.
+pub struct PostDfsSizeIter<'a, T: NodeContent> {
+    tree: &'a Tree<T>,
+    pila: Vec<(usize, bool)>,
+    // Sizes accumulated by the nodes whose children are being visited
+    sizes: Vec<usize>
+}
+
+impl<'a, T: NodeContent> PostDfsSizeIter<'a, T> {
+    pub fn new(tree: &'a Tree<T>) -> Self {
+        Self::new_at(tree, 0)
+    }
+
+    pub fn new_at(tree: &'a Tree<T>, initial_node: usize) -> Self {
+        Self {
+            tree,
+            pila: if tree.get_nodes_len() > initial_node { vec!((initial_node, true)) } else { vec!() },
+            sizes: vec!()
+        }
+    }
+}
+
+impl<'a, T: NodeContent> Iterator for PostDfsSizeIter<'a, T> {
+    type Item = (&'a Node<T>, usize, usize);
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((position, push_children)) = self.pila.pop() {
+            let node = self.tree.get_nodes_ref().get(position)?;
+            let size = if push_children {
+                // Put in the stack all linked children of current node
+                let linked_children: Vec<usize> = node.get_children_ref().iter().copied().filter(|child| *child < self.tree.get_nodes_len()).collect();
+                if !linked_children.is_empty() {
+                    self.pila.push((position, false));
+                    self.sizes.push(0);
+                    for child in linked_children.iter().rev() {
+                        self.pila.push((*child, true));
+                    }
+                    continue;
+                }
+                // No children, a leaf
+                1
+            }
+            else {
+                // All children visited, get accumulated size
+                self.sizes.pop().unwrap_or(0) + 1
+            };
+            // Accumulate size in parent
+            if let Some(parent_size) = self.sizes.last_mut() {
+                *parent_size += size;
+            }
+            return Some((node, position, size));
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every node in the stack will be returned
+        (self.pila.len(), None)
+    }
+}
+
+/// Pre-Order DFS Paths Iterator.
+pub struct PreorderPathsIter<'a, T: NodeContent> {
+    tree: &'a Tree<T>,
+    // Node index and number of ancestors
+    pila: Vec<(usize, usize)>,
+    // Path of the last node returned
+    path: Vec<String>
+}
+
+impl<'a, T: NodeContent> PreorderPathsIter<'a, T> {
+    pub fn new(tree: &'a Tree<T>) -> Self {
+        Self::new_at(tree, 0)
+    }
+
+    pub fn new_at(tree: &'a Tree<T>, initial_node: usize) -> Self {
+        Self {
+            tree,
+            pila: if tree.get_nodes_len() > initial_node { vec!((initial_node, 0)) } else { vec!() },
+            path: vec!()
+        }
+    }
+}
+
+impl<'a, T: NodeContent> Iterator for PreorderPathsIter<'a, T> {
+    type Item = Vec<String>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (position, depth) = self.pila.pop()?;
+        let node = self.tree.get_nodes_ref().get(position)?;
+        // Keep only the ancestors of current node
+        self.path.truncate(depth);
+        self.path.push(String::from(node.get_content_ref().key()));
+        // Put in the stack all linked children of current node
+        for child in node.get_children_ref().iter().rev() {
+            if *child < self.tree.get_nodes_len() {
+                self.pila.push((*child, depth + 1));
+            }
+        }
+        Some(self.path.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.pila.len(), None)
+    }
 }
\ No newline at end of file
@@ -232,6 +232,37 @@
 //!     println!("Node content = `{}` weight = {}", cref.get_val(), cref.get_weight());
 //! }
 //! ```
+//! 
+//! ## No std
+//! 
+//! `Socarel` can be used in `no_std` environments by disabling the default `std` feature. It only requires the `alloc` crate, and uses a [`BTreeMap`](https://doc.rust-lang.org/alloc/collections/btree_map/struct.BTreeMap.html) instead of a `HashMap` internally:
+//! 
+//! ```toml
+//! [dependencies]
+//! socarel = { version = "0.4", default-features = false }
+//! ```
+//! 
+//! Everything works the same way using only the `alloc` collections, this example is also tested with `cargo test --no-default-features`:
+//! 
+//! ```
+//! extern crate alloc;
+//! use alloc::{collections::BTreeMap, string::String, vec::Vec};
+//! use socarel::*;
+//! 
+//! let mut tree = <Tree>::new();
+//! let _root = tree.set_root("root_node").unwrap();
+//! let _child_1 = tree.link_node("child_1", _root).unwrap();
+//! let _child_2 = tree.link_node("child_2", _root).unwrap();
+//! let paths: BTreeMap<String, usize> = tree.path_index('/').into_iter().collect();
+//! assert_eq!(paths.get("root_node/child_2"), Some(&_child_2));
+//! let children: Vec<&str> = tree.children_content(_root).map(|content| content.get_val()).collect();
+//! assert_eq!(children, ["child_1", "child_2"]);
+//! ```
+
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
 
 mod node;
 mod tree;
@@ -244,4 +275,5 @@ pub use forest::*;
 pub use iter::*;
 
 #[cfg(test)]
+#[allow(clippy::redundant_pattern_matching)]
 mod tests;
\ No newline at end of file
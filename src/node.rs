@@ -1,4 +1,8 @@
+#[cfg(feature = "std")]
 use std::collections::HashMap as Map;
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeMap as Map, string::String, vec, vec::Vec};
+use core::fmt;
 
 //---- Structs ----//
 
@@ -33,6 +37,34 @@ pub trait NodeContent {
     /// * Node content.
     ///
     fn gen_content(&self) -> String;
+
+    /// Get the key that identifies the node among its siblings.
+    /// 
+    /// Used in the child map and to resolve paths, while [`NodeContent::get_val()`] is used to display the node. By default it's the node value. Override it when the display value is not a good identifier, for example when it contains human text.
+    /// 
+    /// # Return
+    /// 
+    /// * Key string.
+    ///
+    fn key(&self) -> &str {
+        self.get_val()
+    }
+
+    /// Check if node matches a query.
+    /// 
+    /// Used by [`Tree::find_node()`][`crate::Tree::find_node()`] when the query is not found by exact key. By default it compares the query with [`NodeContent::key()`]. Override to implement custom matching, like case-insensitive.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `query` - Path element to match.
+    /// 
+    /// # Return
+    /// 
+    /// * True if node matches the query.
+    ///
+    fn matches(&self, query: &str) -> bool {
+        self.key() == query
+    }
 }
 
 /// Default [`NodeContent`] struct.
@@ -62,6 +94,32 @@ impl NodeContent for RawNode {
     }
 }
 
+impl RawNode {
+    /// Consume the node content and get the inner string.
+    /// 
+    /// # Return
+    /// 
+    /// * Content string.
+    ///
+    pub fn into_inner(self) -> String {
+        self.content
+    }
+}
+
+impl From<&str> for RawNode {
+    fn from(content: &str) -> Self {
+        Self {
+            content: String::from(content)
+        }
+    }
+}
+
+impl From<String> for RawNode {
+    fn from(content: String) -> Self {
+        Self { content }
+    }
+}
+
 /// Struct that contains a tree node.
 #[derive(Debug)]
 pub struct Node<T: NodeContent = RawNode> {
@@ -69,6 +127,8 @@ pub struct Node<T: NodeContent = RawNode> {
     content: T,
     /// Nodel level.
     level: usize,
+    /// Node depth, the distance from the root node.
+    depth: usize,
     /// Parent node index in the tree array.
     parent_position: Option<usize>,
     // Map of content/node index, to find a child by name.
@@ -113,6 +173,8 @@ impl<T: NodeContent> Node<T> {
                 Node {
                     content: content_node,
                     level,
+                    // Assume the default root level, the tree sets the actual depth
+                    depth: level.saturating_sub(1),
                     parent_position: None,
                     child_map: Map::new(),
                     parents_children_pos: None,
@@ -125,6 +187,28 @@ impl<T: NodeContent> Node<T> {
         }
     }
 
+    /// Convert the node to another content type, keeping its relations.
+    /// 
+    /// The new content is parsed from the generated content of the current one. The child map is copied as is, if the new content type produces different values, it must be rebuilt.
+    /// 
+    /// # Return
+    /// 
+    /// * Node struct or None if content parsing fails.
+    ///
+    pub fn map<U: NodeContent>(&self) -> Option<Node<U>> {
+        Some(
+            Node {
+                content: U::new(&self.content.gen_content())?,
+                level: self.level,
+                depth: self.depth,
+                parent_position: self.parent_position,
+                child_map: self.child_map.clone(),
+                parents_children_pos: self.parents_children_pos,
+                children: self.children.clone()
+            }
+        )
+    }
+
     /// Set content.
     /// 
     /// # Arguments
@@ -159,8 +243,22 @@ impl<T: NodeContent> Node<T> {
         &self.content
     }
 
+    /// Get mutable content reference.
+    /// 
+    /// **WARNING**: changing the key returned by [`NodeContent::key()`] desyncs the parent's child map, use [`Tree::update_node()`](crate::Tree::update_node) for that.
+    /// 
+    /// # Return
+    /// 
+    /// * Mutable content reference.
+    ///
+    pub fn get_mut_content_ref(&mut self) -> &mut T {
+        &mut self.content
+    }
+
     /// Set level.
     /// 
+    /// The level of a linked node must be kept in sync with its depth, see [`Node::set_depth()`].
+    /// 
     /// # Arguments
     /// 
     /// * `level` - Node level.
@@ -175,6 +273,8 @@ impl<T: NodeContent> Node<T> {
 
     /// Get level.
     /// 
+    /// Levels start at 1 for the root node. For 0-based depth use [`Node::get_depth()`].
+    /// 
     /// # Return
     /// 
     /// * Node level.
@@ -183,16 +283,87 @@ impl<T: NodeContent> Node<T> {
         self.level
     }
 
-    /// Get number of children.
+    /// Set depth.
+    /// 
+    /// The depth of a linked node must be kept in sync with its level, [`Tree::validate()`](crate::Tree::validate) reports any mismatch.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `depth` - Node depth.
+    /// 
+    /// # Return
+    /// 
+    /// * Nothing.
+    ///
+    pub fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+    }
+
+    /// Get depth, the distance from the root node.
+    /// 
+    /// Unlike the level, depth starts at 0 for the root node, regardless of the root level of the tree, see [`Tree::new_with_root_level()`](crate::Tree::new_with_root_level).
+    /// 
+    /// # Return
+    /// 
+    /// * Node depth.
+    ///
+    pub fn get_depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Get number of children, including the unlinked ones.
     /// 
     /// # Return
     /// 
     /// * Number of children.
     ///
-    pub fn get_num_chuildren(&self) -> usize {
+    pub fn get_num_children(&self) -> usize {
         self.children.len()
     }
 
+    /// Get number of children, including the unlinked ones.
+    /// 
+    /// Misspelled alias of [`Node::get_num_children()`].
+    /// 
+    /// # Return
+    /// 
+    /// * Number of children.
+    ///
+    #[deprecated(note = "Use `get_num_children` instead")]
+    pub fn get_num_chuildren(&self) -> usize {
+        self.get_num_children()
+    }
+
+    /// Get degree, the number of linked children.
+    /// 
+    /// # Return
+    /// 
+    /// * Number of children, excluding the unlinked ones.
+    ///
+    pub fn degree(&self) -> usize {
+        self.children.iter().filter(|child| **child != usize::MAX).count()
+    }
+
+    /// Check if the node is a leaf, a node without linked children.
+    /// 
+    /// # Return
+    /// 
+    /// * True if there are no linked children.
+    ///
+    pub fn is_leaf(&self) -> bool {
+        self.children.iter().all(|child| *child == usize::MAX)
+    }
+
+    /// Check if the node is a root, a node without parent.
+    /// 
+    /// # Return
+    /// 
+    /// * True if there is no parent.
+    ///
+    pub fn is_root(&self) -> bool {
+        self.parent_position.is_none()
+    }
+
     /// Set parent node position.
     /// 
     /// # Arguments
@@ -239,6 +410,39 @@ impl<T: NodeContent> Node<T> {
         self.parents_children_pos
     }
 
+    /// Remove parent, making it a root node.
+    /// 
+    /// # Return
+    /// 
+    /// * Nothing.
+    ///
+    pub fn clear_parent(&mut self) {
+        self.parent_position = None;
+        self.parents_children_pos = None;
+    }
+
+    /// Change the node indexes referenced by this node, the parent position, the children array and the child map.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - Function that receives a node index and returns the new index. Unlinked children are not passed.
+    /// 
+    /// # Return
+    /// 
+    /// * Nothing.
+    ///
+    pub fn remap_indexes<F: Fn(usize) -> usize>(&mut self, f: F) {
+        self.parent_position = self.parent_position.map(&f);
+        for child in self.children.iter_mut() {
+            if *child != usize::MAX {
+                *child = f(*child);
+            }
+        }
+        for child in self.child_map.values_mut() {
+            *child = f(*child);
+        }
+    }
+
     /// Add new child.
     /// 
     /// # Arguments
@@ -270,6 +474,18 @@ impl<T: NodeContent> Node<T> {
         self.children[node_index] = usize::MAX;
     }
 
+    /// Remove the holes left by unlinked children in the children array.
+    /// 
+    /// The position of the remaining children changes, their `parents_children_pos` must be updated.
+    /// 
+    /// # Return
+    /// 
+    /// * Nothing.
+    ///
+    pub fn compact_children(&mut self) {
+        self.children.retain(|child| *child != usize::MAX);
+    }
+
     /// Update child map.
     /// 
     /// # Arguments
@@ -289,6 +505,40 @@ impl<T: NodeContent> Node<T> {
         None
     }
 
+    /// Exchange the node indexes of two children in the child map.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_content_a` - First child content.
+    /// * `node_content_b` - Second child content.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with nothing, or None if any child is not in the map.
+    ///
+    pub fn swap_children(&mut self, node_content_a: &str, node_content_b: &str) -> Option<()> {
+        let node_index_a = self.get_child(node_content_a)?;
+        let node_index_b = self.get_child(node_content_b)?;
+        self.child_map.insert(String::from(node_content_a), node_index_b);
+        self.child_map.insert(String::from(node_content_b), node_index_a);
+        Some(())
+    }
+
+    /// Replace the child map.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `entries` - Pairs of child content and node index.
+    /// 
+    /// # Return
+    /// 
+    /// * Nothing.
+    ///
+    pub fn rebuild_child_map<I: IntoIterator<Item = (String, usize)>>(&mut self, entries: I) {
+        self.child_map.clear();
+        self.child_map.extend(entries);
+    }
+
     /// Get child index using node content.
     /// 
     /// # Arguments
@@ -300,12 +550,18 @@ impl<T: NodeContent> Node<T> {
     /// * An [`Option`] with the node index.
     ///
     pub fn get_child(&self, node_content: &str) -> Option<usize> {
-        if let Some(node_index) = self.child_map.get(node_content) {
-            Some(*node_index)
-        }
-        else {
-            None
-        }
+        self.child_map.get(node_content).copied()
+    }
+
+    /// Remove all children, clearing the children array and the child map.
+    /// 
+    /// # Return
+    /// 
+    /// * Nothing.
+    ///
+    pub fn clear_children(&mut self) {
+        self.children.clear();
+        self.child_map.clear();
     }
 
     /// Get children array reference.
@@ -317,4 +573,23 @@ impl<T: NodeContent> Node<T> {
     pub fn get_children_ref(&self) -> &[usize] {
         &self.children
     }
+
+    /// Get child map reference, the map of child keys ([`NodeContent::key()`]) to node indexes used to find nodes by path.
+    /// 
+    /// Without the `std` feature the map is a BTreeMap.
+    /// 
+    /// # Return
+    /// 
+    /// * Map ref.
+    ///
+    pub fn get_child_map(&self) -> &Map<String, usize> {
+        &self.child_map
+    }
+}
+
+/// Print the node content value.
+impl<T: NodeContent> fmt::Display for Node<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.content.get_val())
+    }
 }
\ No newline at end of file
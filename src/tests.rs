@@ -1,6 +1,7 @@
 use crate::forest::*;
 use crate::tree::*;
 use crate::node::*;
+use crate::iter::*;
 
 fn forest_sample() -> Forest {
     let mut forest = <Forest>::new();
@@ -16,6 +17,69 @@ fn forest_sample() -> Forest {
     forest
 }
 
+/*
+ * Sample tree:
+ *
+ *        A
+ *      /   \
+ *     B     C
+ *    / \   / \
+ *   D   E F   G
+ *       |
+ *       H
+ */
+fn tree_sample() -> Tree {
+    let mut tree = <Tree>::new();
+    let _a = tree.set_root("A").unwrap();
+    let _b = tree.link_node("B", _a).unwrap();
+    let _c = tree.link_node("C", _a).unwrap();
+    let _d = tree.link_node("D", _b).unwrap();
+    let _e = tree.link_node("E", _b).unwrap();
+    let _f = tree.link_node("F", _c).unwrap();
+    let _g = tree.link_node("G", _c).unwrap();
+    let _h = tree.link_node("H", _e).unwrap();
+    tree
+}
+
+// Node content with a weight, in the format "weight:value"
+#[derive(Debug)]
+struct WeightNode {
+    content: String,
+    weight: u32
+}
+
+impl WeightNode {
+    fn get_weight(&self) -> u32 {
+        self.weight
+    }
+}
+
+impl NodeContent for WeightNode {
+    fn new(content: &str) -> Option<Self> {
+        let vec: Vec<&str> = content.split(':').collect();
+        if vec.len() == 2 {
+            match vec[0].trim().parse() {
+                Ok(num) => Some(Self {
+                    content: String::from(vec[1]),
+                    weight: num
+                }),
+                Err(_) => None
+            }
+        }
+        else {
+            None
+        }
+    }
+
+    fn get_val(&self) -> &str {
+        &self.content
+    }
+
+    fn gen_content(&self) -> String {
+        format!("{}:{}", self.weight, self.content)
+    }
+}
+
 #[test]
 fn check_tree_integrity() {
     let forest = forest_sample();
@@ -25,7 +89,7 @@ fn check_tree_integrity() {
                 0 => {
                     if !n.get_content_ref().get_val().eq("root_node") { panic!("Wrong root_node content") }
                     if let Some(_) = n.get_parent_position() { panic!("root_node has a parent") }
-                    if n.get_num_chuildren() != 3 { panic!("root_node hasn't 3 children") }
+                    if n.get_num_children() != 3 { panic!("root_node hasn't 3 children") }
                     if n.get_children_ref()[0] != 1 || n.get_children_ref()[1] != 2 || n.get_children_ref()[2] != 6 { panic!("root_node children are incorrect") }
                 },
                 1 => {
@@ -36,7 +100,7 @@ fn check_tree_integrity() {
                             panic!("child_1 has wrong parent");
                         }
                     }
-                    if n.get_num_chuildren() != 0 { panic!("child_1 hasn't 0 children"); }
+                    if n.get_num_children() != 0 { panic!("child_1 hasn't 0 children"); }
                 },
                 2 => {
                     if !n.get_content_ref().get_val().eq("child_2") { panic!("Wrong child_2 content"); }
@@ -46,7 +110,7 @@ fn check_tree_integrity() {
                             panic!("child_2 has wrong parent");
                         }
                     }
-                    if n.get_num_chuildren() != 2 { panic!("child_2 hasn't 2 children"); }
+                    if n.get_num_children() != 2 { panic!("child_2 hasn't 2 children"); }
                     if n.get_children_ref()[0] != 3 || n.get_children_ref()[1] != 5 { panic!("child_2 children are incorrect"); }
                 },
                 3 => {
@@ -57,7 +121,7 @@ fn check_tree_integrity() {
                             panic!("child_2_1 has wrong parent");
                         }
                     }
-                    if n.get_num_chuildren() != 1 { panic!("child_2_1 hasn't 1 child"); }
+                    if n.get_num_children() != 1 { panic!("child_2_1 hasn't 1 child"); }
                     if n.get_children_ref()[0] != 4 { panic!("child_2_1 children are incorrect"); }
                 },
                 4 => {
@@ -68,7 +132,7 @@ fn check_tree_integrity() {
                             panic!("child_2_1_1 has wrong parent");
                         }
                     }
-                    if n.get_num_chuildren() != 0 { panic!("child_2_1_1 hasn't 0 children"); }
+                    if n.get_num_children() != 0 { panic!("child_2_1_1 hasn't 0 children"); }
                 },
                 5 => {
                     if !n.get_content_ref().get_val().eq("child_2_2") { panic!("Wrong child_2_2 content"); }
@@ -78,7 +142,7 @@ fn check_tree_integrity() {
                             panic!("child_2_2 has wrong parent");
                         }
                     }
-                    if n.get_num_chuildren() != 0 { panic!("child_2_2 hasn't 0 children"); }
+                    if n.get_num_children() != 0 { panic!("child_2_2 hasn't 0 children"); }
                 },
                 6 => {                          
                     if !n.get_content_ref().get_val().eq("child_3") { panic!("Wrong child_3 content"); }
@@ -88,7 +152,7 @@ fn check_tree_integrity() {
                             panic!("child_3 has wrong parent");
                         }
                     }
-                    if n.get_num_chuildren() != 0 { panic!("child_3 hasn't 0 children"); }
+                    if n.get_num_children() != 0 { panic!("child_3 hasn't 0 children"); }
                 }
                 _ => {}
             }
@@ -125,6 +189,9 @@ fn mutate_and_check_integrity() {
             3 => {
                 if !n.get_content_ref().get_val().eq("child_3") { panic!("Wrong child_3 content") }
             },
+            4 => {
+                if !n.get_content_ref().get_val().eq("child_2_2") { panic!("Wrong child_2_2 content") }
+            },
             _ => {
                 panic!("Invalid number of nodes");
             }
@@ -201,4 +268,1876 @@ fn check_custom_node_content() {
     }
 }
 
+#[test]
+fn check_children_iter_bounds() {
+    // Empty tree, no root node to iterate
+    let tree = <Tree>::new();
+    assert_eq!(tree.iterators().children().count(), 0);
+    // Out of range initial node
+    let forest = forest_sample();
+    let tree = forest.get_tree("test_tree").unwrap();
+    assert_eq!(ChildrenIter::new(tree, 100).count(), 0);
+    // Unlinked initial node
+    let mut tree = <Tree>::new();
+    let _root = tree.set_root("root_node").unwrap();
+    let _child_1 = tree.link_node("child_1", _root).unwrap();
+    let _child_1_1 = tree.link_node("child_1_1", _child_1).unwrap();
+    let _child_2 = tree.link_node("child_2", _root).unwrap();
+    tree.unlink_node(_child_1).unwrap();
+    assert_eq!(ChildrenIter::new(&tree, _child_1).count(), 0);
+    // Unlinked children are skipped
+    let children: Vec<usize> = tree.iterators().children().map(|(_, i)| i).collect();
+    assert_eq!(children, vec!(_child_2));
+}
+
+#[test]
+fn check_parent_and_children_indexes() {
+    let mut tree = tree_sample();
+    let h = tree.find_node(&["A", "B", "E", "H"]).unwrap();
+    let e = tree.find_node(&["A", "B", "E"]).unwrap();
+    assert_eq!(tree.parent(h), Some(e));
+    assert_eq!(tree.parent(0), None);
+    assert_eq!(tree.parent(100), None);
+    let b = tree.find_node(&["A", "B"]).unwrap();
+    let c = tree.find_node(&["A", "C"]).unwrap();
+    assert_eq!(tree.children_indexes(0), Some(vec!(b, c)));
+    tree.unlink_node(b).unwrap();
+    assert_eq!(tree.children_indexes(0), Some(vec!(c)));
+    assert_eq!(tree.children_indexes(100), None);
+}
+
+#[test]
+fn check_forest_merge() {
+    let mut forest = forest_sample();
+    let mut other = <Forest>::new();
+    other.add_tree("sample_tree", tree_sample());
+    other.new_tree("empty_tree");
+    forest.merge(other).expect("Could not merge forests");
+    assert!(forest.get_tree("test_tree").is_some());
+    assert!(forest.get_tree("sample_tree").is_some());
+    assert!(forest.get_tree("empty_tree").is_some());
+    // Colliding tree name
+    let mut colliding = <Forest>::new();
+    colliding.new_tree("other_tree");
+    colliding.new_tree("test_tree");
+    assert_eq!(forest.merge(colliding), Err(String::from("test_tree")));
+    assert!(forest.get_tree("other_tree").is_none());
+}
+
+#[test]
+fn check_forest_into_iter() {
+    let mut forest = forest_sample();
+    forest.add_tree("sample_tree", tree_sample());
+    let mut new_forest = <Forest>::new();
+    for (name, tree) in forest {
+        new_forest.add_tree(&name, tree);
+    }
+    assert_eq!(new_forest.get_tree("test_tree").unwrap().get_nodes_len(), 7);
+    assert_eq!(new_forest.get_tree("sample_tree").unwrap().get_nodes_len(), 8);
+    assert_eq!(new_forest.iter().count(), 2);
+}
+
+#[test]
+fn check_link_under_unlinked_parent() {
+    let mut tree = tree_sample();
+    let b = tree.find_node(&["A", "B"]).unwrap();
+    let e = tree.find_node(&["A", "B", "E"]).unwrap();
+    tree.unlink_node(b).unwrap();
+    assert!(tree.link_node("I", b).is_none());
+    // Descendant of an unlinked node is unreachable too
+    assert!(tree.link_node("I", e).is_none());
+    assert!(tree.link_node("I", 100).is_none());
+    assert!(tree.link_node("I", 0).is_some());
+}
+
+#[test]
+fn check_custom_node_matches() {
+    #[derive(Debug)]
+    struct CaseInsensitiveNode {
+        content: String
+    }
+
+    impl NodeContent for CaseInsensitiveNode {
+        fn new(content: &str) -> Option<Self> {
+            Some(Self { content: String::from(content) })
+        }
+
+        fn get_val(&self) -> &str {
+            &self.content
+        }
+
+        fn gen_content(&self) -> String {
+            String::from(self.get_val())
+        }
+
+        fn matches(&self, query: &str) -> bool {
+            self.content.to_lowercase() == query.to_lowercase()
+        }
+    }
+
+    let mut tree = Tree::<CaseInsensitiveNode>::new();
+    let _root = tree.set_root("Root_Node").unwrap();
+    let _child_1 = tree.link_node("Child_1", _root).unwrap();
+    let _child_1_1 = tree.link_node("Child_1_1", _child_1).unwrap();
+    assert_eq!(tree.find_node(&["Root_Node", "Child_1", "Child_1_1"]), Some(_child_1_1));
+    assert_eq!(tree.find_node(&["root_node", "CHILD_1", "child_1_1"]), Some(_child_1_1));
+    assert_eq!(tree.find_node(&["root_node", "child_2"]), None);
+    // Default implementation is exact
+    let tree = tree_sample();
+    assert_eq!(tree.find_node(&["a", "b"]), None);
+}
+
+#[test]
+fn check_node_path() {
+    let tree = tree_sample();
+    let h = tree.find_node(&["A", "B", "E", "H"]).unwrap();
+    assert_eq!(tree.node_path(h), Some(vec!(String::from("A"), String::from("B"), String::from("E"), String::from("H"))));
+    assert_eq!(tree.node_path(0), Some(vec!(String::from("A"))));
+    assert_eq!(tree.node_path(100), None);
+}
+
+#[test]
+fn check_ancestors_and_descendants() {
+    let tree = tree_sample();
+    let a = tree.find_node(&["A"]).unwrap();
+    let b = tree.find_node(&["A", "B"]).unwrap();
+    let c = tree.find_node(&["A", "C"]).unwrap();
+    let h = tree.find_node(&["A", "B", "E", "H"]).unwrap();
+    assert!(tree.is_ancestor(a, h));
+    assert!(tree.is_ancestor(b, h));
+    assert!(!tree.is_ancestor(c, h));
+    assert!(!tree.is_ancestor(h, a));
+    assert!(!tree.is_ancestor(h, h));
+    assert!(tree.is_descendant(h, a));
+    assert!(!tree.is_descendant(h, c));
+}
+
+#[test]
+fn check_level_iter() {
+    let tree = tree_sample();
+    let levels: Vec<(usize, Vec<&str>)> = tree.iterators().by_level().map(|(level, nodes)| {
+        (level, nodes.iter().map(|(n, _)| n.get_content_ref().get_val()).collect())
+    }).collect();
+    assert_eq!(levels, vec!(
+        (1, vec!("A")),
+        (2, vec!("B", "C")),
+        (3, vec!("D", "E", "F", "G")),
+        (4, vec!("H"))
+    ));
+    assert_eq!(<Tree>::new().iterators().by_level().count(), 0);
+}
+
+#[test]
+fn check_num_children() {
+    let mut tree = <Tree>::new();
+    let _root = tree.set_root("root_node").unwrap();
+    let _child_1 = tree.link_node("child_1", _root).unwrap();
+    let _child_2 = tree.link_node("child_2", _root).unwrap();
+    let _child_3 = tree.link_node("child_3", _root).unwrap();
+    assert_eq!(tree.num_children(_root), Some(3));
+    tree.unlink_node(_child_2).unwrap();
+    assert_eq!(tree.num_children(_root), Some(2));
+    assert_eq!(tree.num_children(_child_1), Some(0));
+    assert_eq!(tree.num_children(100), None);
+}
+
+#[test]
+#[allow(deprecated)]
+fn check_num_children_alias() {
+    let tree = tree_sample();
+    for (node, _) in tree.iterators().sequential() {
+        assert_eq!(node.get_num_children(), node.get_num_chuildren());
+    }
+}
+
+#[test]
+fn check_get_node() {
+    let mut tree = tree_sample();
+    let b = tree.find_node(&["A", "B"]).unwrap();
+    let h = tree.find_node(&["A", "B", "E", "H"]).unwrap();
+    let node = tree.get_node(h).expect("Could not get node");
+    assert_eq!(node.get_content_ref().get_val(), "H");
+    assert_eq!(node.get_level(), 4);
+    assert!(tree.get_node(100).is_none());
+    tree.unlink_node(b).unwrap();
+    assert!(tree.get_node(b).is_none());
+    assert!(tree.get_node(h).is_none());
+}
+
+#[test]
+fn check_tree_capacity() {
+    let mut tree = <Tree>::with_capacity(100);
+    assert!(tree.capacity() >= 100);
+    let _root = tree.set_root("root_node").unwrap();
+    let nodes_ptr = tree.get_nodes_ref().as_ptr();
+    for i in 1..100 {
+        tree.link_node(&format!("child_{}", i), _root).unwrap();
+    }
+    // No reallocation happened
+    assert_eq!(tree.get_nodes_ref().as_ptr(), nodes_ptr);
+    tree.reserve(100);
+    assert!(tree.capacity() >= 200);
+    let forest = <Forest>::with_capacity(10);
+    assert_eq!(forest.iter().count(), 0);
+}
+
+#[test]
+fn check_tree_shrink_to_fit() {
+    let mut tree = tree_sample();
+    tree.reserve(1000);
+    assert!(tree.capacity() >= 1008);
+    tree.shrink_to_fit();
+    assert!(tree.capacity() < 1008);
+    assert!(tree.capacity() >= tree.get_nodes_len());
+}
+
+#[test]
+fn check_dfs_events_iter() {
+    let mut tree = <Tree>::new();
+    let _root = tree.set_root("root_node").unwrap();
+    let _child_1 = tree.link_node("child_1", _root).unwrap();
+    let _child_2 = tree.link_node("child_2", _root).unwrap();
+    let events: Vec<String> = tree.iterators().dfs_events().map(|event| {
+        match event {
+            DfsEvent::Enter(n, i) => format!("enter {} {}", n.get_content_ref().get_val(), i),
+            DfsEvent::Leave(n, i) => format!("leave {} {}", n.get_content_ref().get_val(), i)
+        }
+    }).collect();
+    assert_eq!(events, vec!(
+        "enter root_node 0",
+        "enter child_1 1",
+        "leave child_1 1",
+        "enter child_2 2",
+        "leave child_2 2",
+        "leave root_node 0"
+    ));
+    assert_eq!(<Tree>::new().iterators().dfs_events().count(), 0);
+}
+
+#[test]
+fn check_walk_path() {
+    let forest = forest_sample();
+    let tree = forest.get_tree("test_tree").unwrap();
+    let child_2 = tree.find_node(&["root_node", "child_2"]).unwrap();
+    let child_2_1 = tree.find_node(&["root_node", "child_2", "child_2_1"]).unwrap();
+    assert_eq!(tree.walk_path(0, &["child_2", "child_2_1"]), Some(vec!(0, child_2, child_2_1)));
+    assert_eq!(tree.walk_path(child_2, &["child_2_1"]), Some(vec!(child_2, child_2_1)));
+    assert_eq!(tree.walk_path(0, &[]), Some(vec!(0)));
+    assert_eq!(tree.walk_path(0, &["child_2", "child_3"]), None);
+    assert_eq!(tree.walk_path(100, &["child_2"]), None);
+}
+
+#[test]
+fn check_iter_size_hint() {
+    let tree = tree_sample();
+    let mut iter = tree.iterators().sequential();
+    assert_eq!(iter.size_hint(), (8, Some(8)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (7, Some(7)));
+    let mut iter = tree.iterators().inv_sequential();
+    assert_eq!(iter.size_hint(), (8, Some(8)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (7, Some(7)));
+    assert_eq!(tree.iterators().bfs().size_hint().0, 1);
+    assert_eq!(tree.iterators().pre_dfs().size_hint().0, 1);
+    assert_eq!(tree.iterators().children().size_hint(), (0, Some(2)));
+    // Size hints never exceed the actual number of elements
+    assert!(tree.iterators().bfs().size_hint().0 <= tree.iterators().bfs().count());
+    assert!(tree.iterators().post_dfs().size_hint().0 <= tree.iterators().post_dfs().count());
+    assert!(tree.iterators().dfs_events().size_hint().0 <= tree.iterators().dfs_events().count());
+    let empty_tree = <Tree>::new();
+    assert_eq!(empty_tree.iterators().sequential().size_hint(), (0, Some(0)));
+    assert_eq!(empty_tree.iterators().inv_sequential().size_hint(), (0, Some(0)));
+    assert_eq!(empty_tree.iterators().bfs().size_hint().0, 0);
+}
+
+#[test]
+fn check_seq_iter_rev() {
+    let tree = tree_sample();
+    let rev: Vec<usize> = tree.iterators().sequential().rev().map(|(_, i)| i).collect();
+    let inv: Vec<usize> = tree.iterators().inv_sequential().map(|(_, i)| i).collect();
+    assert_eq!(rev, inv);
+    assert_eq!(rev, vec!(7, 6, 5, 4, 3, 2, 1, 0));
+    // Both ends meet in the middle
+    let mut iter = tree.iterators().sequential();
+    assert_eq!(iter.next().map(|(_, i)| i), Some(0));
+    assert_eq!(iter.next_back().map(|(_, i)| i), Some(7));
+    assert_eq!(iter.size_hint(), (6, Some(6)));
+    assert_eq!(iter.count(), 6);
+    assert!(<Tree>::new().iterators().sequential().next_back().is_none());
+}
+
+#[test]
+fn check_post_dfs_with_size_iter() {
+    let tree = tree_sample();
+    let sizes: Vec<(&str, usize)> = tree.iterators().post_dfs_with_size().map(|(n, _, size)| (n.get_content_ref().get_val(), size)).collect();
+    assert_eq!(sizes, vec!(("D", 1), ("H", 1), ("E", 2), ("B", 4), ("F", 1), ("G", 1), ("C", 3), ("A", 8)));
+    // Same order as the Post-Order DFS iterator
+    let post_dfs: Vec<usize> = tree.iterators().post_dfs().map(|(_, i)| i).collect();
+    let post_dfs_size: Vec<usize> = tree.iterators().post_dfs_with_size().map(|(_, i, _)| i).collect();
+    assert_eq!(post_dfs, post_dfs_size);
+    assert_eq!(<Tree>::new().iterators().post_dfs_with_size().count(), 0);
+}
+
+#[test]
+fn check_subtree_size() {
+    let mut tree = tree_sample();
+    let b = tree.find_node(&["A", "B"]).unwrap();
+    let e = tree.find_node(&["A", "B", "E"]).unwrap();
+    let h = tree.find_node(&["A", "B", "E", "H"]).unwrap();
+    assert_eq!(tree.subtree_size(0), Some(8));
+    assert_eq!(tree.subtree_size(b), Some(4));
+    assert_eq!(tree.subtree_size(h), Some(1));
+    assert_eq!(tree.subtree_size(100), None);
+    tree.unlink_node(e).unwrap();
+    assert_eq!(tree.subtree_size(b), Some(2));
+    assert_eq!(tree.subtree_size(0), Some(6));
+    // Unlinked nodes are not reachable
+    assert_eq!(tree.subtree_size(e), None);
+    assert_eq!(tree.subtree_size(h), None);
+}
+
+#[test]
+fn check_common_prefix() {
+    let tree = tree_sample();
+    let b = tree.find_node(&["A", "B"]).unwrap();
+    let d = tree.find_node(&["A", "B", "D"]).unwrap();
+    let e = tree.find_node(&["A", "B", "E"]).unwrap();
+    let f = tree.find_node(&["A", "C", "F"]).unwrap();
+    let h = tree.find_node(&["A", "B", "E", "H"]).unwrap();
+    assert_eq!(tree.common_prefix(d, f), vec!(0));
+    assert_eq!(tree.common_prefix(d, h), vec!(0, b));
+    assert_eq!(tree.common_prefix(h, e), vec!(0, b, e));
+    assert_eq!(tree.common_prefix(h, h), vec!(0, b, e, h));
+    assert_eq!(tree.common_prefix(h, 100), vec!());
+}
+
+#[test]
+fn check_forest_from_iter() {
+    let forest: Forest = vec!(("tree_1", tree_sample()), ("tree_2", <Tree>::new()), ("tree_3", <Tree>::new())).into_iter().collect();
+    assert_eq!(forest.iter().count(), 3);
+    assert_eq!(forest.get_tree("tree_1").unwrap().get_nodes_len(), 8);
+    // Last tree wins
+    let forest: Forest = vec!((String::from("tree_1"), tree_sample()), (String::from("tree_1"), <Tree>::new())).into_iter().collect();
+    assert_eq!(forest.iter().count(), 1);
+    assert_eq!(forest.get_tree("tree_1").unwrap().get_nodes_len(), 0);
+    // Round trip with into_iter
+    let forest: Forest = forest_sample().into_iter().collect();
+    assert!(forest.get_tree("test_tree").is_some());
+}
+
+#[test]
+fn check_max_children() {
+    let mut tree = <Tree>::with_max_children(2);
+    let _root = tree.set_root("root_node").unwrap();
+    let _child_1 = tree.link_node("child_1", _root).unwrap();
+    let _child_2 = tree.link_node("child_2", _root).unwrap();
+    assert!(tree.link_node("child_3", _root).is_none());
+    assert!(tree.link_node("child_1_1", _child_1).is_some());
+    // Unlinked children don't count
+    tree.unlink_node(_child_2).unwrap();
+    assert!(tree.link_node("child_3", _root).is_some());
+    assert!(tree.link_node("child_4", _root).is_none());
+}
+
+#[test]
+fn check_node_depth() {
+    let tree = tree_sample();
+    let root = tree.get_node(0).unwrap();
+    assert_eq!(root.get_level(), 1);
+    assert_eq!(root.get_depth(), 0);
+    let h = tree.get_node(tree.find_node(&["A", "B", "E", "H"]).unwrap()).unwrap();
+    assert_eq!(h.get_level(), 4);
+    assert_eq!(h.get_depth(), 3);
+}
+
+#[test]
+fn check_leaves_and_internal_count() {
+    let mut tree = tree_sample();
+    assert_eq!(tree.leaves_count(), 4);
+    assert_eq!(tree.internal_count(), 4);
+    let h = tree.find_node(&["A", "B", "E", "H"]).unwrap();
+    tree.unlink_node(h).unwrap();
+    // E becomes a leaf
+    assert_eq!(tree.leaves_count(), 4);
+    assert_eq!(tree.internal_count(), 3);
+    assert_eq!(<Tree>::new().leaves_count(), 0);
+    assert_eq!(<Tree>::new().internal_count(), 0);
+}
+
+#[test]
+fn check_max_breadth() {
+    let mut tree = tree_sample();
+    assert_eq!(tree.max_breadth(), 4);
+    let c = tree.find_node(&["A", "C"]).unwrap();
+    tree.unlink_node(c).unwrap();
+    assert_eq!(tree.max_breadth(), 2);
+    assert_eq!(<Tree>::new().max_breadth(), 0);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn check_par_for_each_bfs() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    let forest = forest_sample();
+    let tree = forest.get_tree("test_tree").unwrap();
+    let par_sum = AtomicUsize::new(0);
+    tree.par_for_each_bfs(|node, _| {
+        par_sum.fetch_add(node.get_content_ref().get_val().len(), Ordering::Relaxed);
+    });
+    let seq_sum: usize = tree.iterators().bfs().map(|(node, _)| node.get_content_ref().get_val().len()).sum();
+    assert_eq!(par_sum.load(Ordering::Relaxed), seq_sum);
+}
+
+fn path(elements: &[&str]) -> Vec<String> {
+    elements.iter().map(|element| String::from(*element)).collect()
+}
+
+// Added edit of a RawNode, its content is the last key of the path
+fn added(elements: &[&str]) -> TreeEdit {
+    TreeEdit::Added { path: path(elements), content: String::from(*elements.last().unwrap()) }
+}
+
+#[test]
+fn check_diff_added_leaf() {
+    let tree = tree_sample();
+    let mut other = tree_sample();
+    let g = other.find_node(&["A", "C", "G"]).unwrap();
+    other.link_node("I", g).unwrap();
+    assert_eq!(tree.diff(&other), vec!(added(&["A", "C", "G", "I"])));
+    assert_eq!(other.diff(&tree), vec!(TreeEdit::Removed(path(&["A", "C", "G", "I"]))));
+    assert!(tree.diff(&tree_sample()).is_empty());
+}
+
+#[test]
+fn check_diff_removed_subtree() {
+    let tree = tree_sample();
+    let mut other = tree_sample();
+    let b = other.find_node(&["A", "B"]).unwrap();
+    other.unlink_node(b).unwrap();
+    assert_eq!(tree.diff(&other), vec!(TreeEdit::Removed(path(&["A", "B"]))));
+    // The other way around, the whole subtree is added
+    assert_eq!(other.diff(&tree), vec!(
+        added(&["A", "B"]),
+        added(&["A", "B", "D"]),
+        added(&["A", "B", "E"]),
+        added(&["A", "B", "E", "H"])
+    ));
+}
+
+#[test]
+fn check_diff_renamed_node() {
+    let tree = tree_sample();
+    let mut other = tree_sample();
+    let e = other.find_node(&["A", "B", "E"]).unwrap();
+    other.update_node("X", e).unwrap();
+    assert_eq!(tree.diff(&other), vec!(
+        TreeEdit::Removed(path(&["A", "B", "E"])),
+        added(&["A", "B", "X"]),
+        added(&["A", "B", "X", "H"])
+    ));
+    // Renamed root
+    let mut other = tree_sample();
+    other.update_node("Z", 0).unwrap();
+    let edits = tree.diff(&other);
+    assert_eq!(edits[0], TreeEdit::Removed(path(&["A"])));
+    assert_eq!(edits[1], added(&["Z"]));
+    assert_eq!(edits.len(), 9);
+    // Empty trees
+    assert_eq!(tree.diff(&<Tree>::new()), vec!(TreeEdit::Removed(path(&["A"]))));
+    assert!(<Tree>::new().diff(&<Tree>::new()).is_empty());
+}
+
+#[test]
+fn check_diff_content_changed() {
+    let mut tree = Tree::<WeightNode>::new();
+    let _root = tree.set_root("0:root_node").unwrap();
+    let _child_1 = tree.link_node("10:child_1", _root).unwrap();
+    let mut other = Tree::<WeightNode>::new();
+    let _root = other.set_root("0:root_node").unwrap();
+    let _child_1 = other.link_node("20:child_1", _root).unwrap();
+    assert_eq!(tree.diff(&other), vec!(TreeEdit::ContentChanged {
+        path: path(&["root_node", "child_1"]),
+        old: String::from("10:child_1"),
+        new: String::from("20:child_1")
+    }));
+}
+
+#[test]
+fn check_fold() {
+    let tree = tree_sample();
+    // Number of descendants
+    let descendants = tree.fold(|_, children: Vec<usize>| children.iter().map(|c| c + 1).sum());
+    assert_eq!(descendants, Some(7));
+    // Rebuild the tree structure as a string
+    let text = tree.fold(|content, children: Vec<String>| {
+        if children.is_empty() {
+            String::from(content.get_val())
+        }
+        else {
+            format!("{}({})", content.get_val(), children.join(","))
+        }
+    });
+    assert_eq!(text, Some(String::from("A(B(D,E(H)),C(F,G))")));
+    assert_eq!(<Tree>::new().fold(|_, _: Vec<usize>| 0), None);
+}
+
+#[test]
+fn check_find_node_empty() {
+    let tree = <Tree>::new();
+    assert_eq!(tree.find_node(&["root_node"]), None);
+    assert_eq!(tree.find_node(&["root_node", "child_1"]), None);
+    assert_eq!(tree.find_node(&[]), None);
+    let tree = tree_sample();
+    assert_eq!(tree.find_node(&[]), None);
+    assert_eq!(tree.find_node(&["A"]), Some(0));
+}
+
+#[test]
+fn check_dead_nodes() {
+    let mut tree = tree_sample();
+    assert!(tree.dead_nodes().is_empty());
+    // Unlink "E", with child "H"
+    tree.unlink_node(4).unwrap();
+    assert_eq!(tree.dead_nodes(), vec!(4, 7));
+    // Unlink "C", with children "F" and "G"
+    tree.unlink_node(2).unwrap();
+    assert_eq!(tree.dead_nodes(), vec!(2, 4, 5, 6, 7));
+    assert!(<Tree>::new().dead_nodes().is_empty());
+}
+
+#[test]
+fn check_prune_dead() {
+    let mut tree = tree_sample();
+    // Unlink "E", with child "H"
+    tree.unlink_node(4).unwrap();
+    tree.prune_dead();
+    assert_eq!(tree.get_nodes_len(), 8);
+    assert!(tree.get_nodes_ref()[4].get_children_ref().is_empty());
+    assert_eq!(tree.get_nodes_ref()[4].get_content_ref().get_val(), "");
+    assert_eq!(tree.get_nodes_ref()[7].get_content_ref().get_val(), "");
+    // Linked nodes are untouched
+    assert_eq!(tree.find_node(&["A"]), Some(0));
+    assert_eq!(tree.find_node(&["A", "C", "G"]), Some(6));
+    assert_eq!(tree.find_node(&["A", "B", "D"]), Some(3));
+}
+
+#[test]
+fn check_display() {
+    let tree = tree_sample();
+    assert_eq!(format!("{}", tree.get_nodes_ref()[0]), "A");
+    assert_eq!(format!("{}", tree.get_node(7).unwrap()), "H");
+    assert_eq!(format!("{}", tree), "A\n  B\n    D\n    E\n      H\n  C\n    F\n    G\n");
+    assert_eq!(format!("{}", <Tree>::new()), "");
+}
+
+#[test]
+fn check_get_mut_nodes_ref() {
+    let mut tree = tree_sample();
+    tree.get_mut_nodes_ref()[7].set_level(10);
+    assert_eq!(tree.get_node(7).unwrap().get_level(), 10);
+    assert_eq!(tree.get_mut_nodes_ref().len(), tree.get_nodes_len());
+}
+
+#[test]
+fn check_validate() {
+    let mut tree = tree_sample();
+    assert_eq!(tree.validate(), Ok(()));
+    tree.unlink_node(4).unwrap();
+    assert_eq!(tree.validate(), Ok(()));
+    assert_eq!(<Tree>::new().validate(), Ok(()));
+    // Corrupt level
+    let mut tree = tree_sample();
+    tree.get_mut_nodes_ref()[7].set_level(10);
+    assert_eq!(tree.validate(), Err(String::from("Node 7 has level 10 but its parent has level 3")));
+    // Corrupt depth
+    let mut tree = tree_sample();
+    tree.get_mut_nodes_ref()[7].set_depth(10);
+    assert_eq!(tree.validate(), Err(String::from("Node 7 has depth 10 but its parent has depth 2")));
+    let mut tree = tree_sample();
+    tree.get_mut_nodes_ref()[0].set_depth(1);
+    assert_eq!(tree.validate(), Err(String::from("Root node has depth 1")));
+    // Corrupt parent
+    let mut tree = tree_sample();
+    tree.get_mut_nodes_ref()[5].set_parent_position(1);
+    assert_eq!(tree.validate(), Err(String::from("Node 5 is child of 2 but its parent is Some(1)")));
+    // Corrupt content, child map is out of sync
+    let mut tree = tree_sample();
+    tree.get_mut_nodes_ref()[3].set_content(RawNode::new("X").unwrap());
+    assert_eq!(tree.validate(), Err(String::from("Node 3 is not in the child map of 1")));
+}
+
+#[test]
+fn check_zip_bfs() {
+    let tree = tree_sample();
+    let mut weights = Tree::<WeightNode>::new();
+    let _a = weights.set_root("0:A").unwrap();
+    let _b = weights.link_node("1:B", _a).unwrap();
+    let _c = weights.link_node("2:C", _a).unwrap();
+    let _d = weights.link_node("3:D", _b).unwrap();
+    let mut count = 0;
+    for ((node, index), (other_node, other_index)) in tree.zip_bfs(&weights) {
+        assert_eq!(node.get_content_ref().get_val(), other_node.get_content_ref().get_val());
+        assert_eq!(index, other_index);
+        assert_eq!(other_node.get_content_ref().get_weight() as usize, other_index);
+        count += 1;
+    }
+    // Stops at the shorter tree
+    assert_eq!(count, 4);
+    assert_eq!(weights.zip_bfs(&tree).count(), 4);
+    assert_eq!(tree.zip_bfs(&<Tree>::new()).count(), 0);
+}
+
+#[test]
+fn check_link_node_checked() {
+    let lighter = |parent: &WeightNode, new: &WeightNode| {
+        if new.get_weight() > parent.get_weight() {
+            Err(format!("\"{}\" is heavier than its parent", new.get_val()))
+        }
+        else {
+            Ok(())
+        }
+    };
+    let mut tree = Tree::<WeightNode>::with_max_children(1);
+    let _root = tree.set_root("10:root_node").unwrap();
+    assert_eq!(tree.link_node_checked("20:child_1", _root, lighter), Err(String::from("\"child_1\" is heavier than its parent")));
+    assert_eq!(tree.get_nodes_len(), 1);
+    assert_eq!(tree.link_node_checked("5:child_1", _root, lighter), Ok(1));
+    assert_eq!(tree.link_node_checked("2:child_2", _root, lighter), Err(String::from("Node 0 can't have more children")));
+    assert_eq!(tree.link_node_checked("child_1_1", 1, lighter), Err(String::from("Invalid node content \"child_1_1\"")));
+    assert_eq!(tree.link_node_checked("1:child_1_1", 5, lighter), Err(String::from("Parent node 5 is not linked")));
+}
+
+#[test]
+fn check_children_content() {
+    let mut tree = tree_sample();
+    let values: Vec<&str> = tree.children_content(0).map(|content| content.get_val()).collect();
+    assert_eq!(values, vec!("B", "C"));
+    tree.unlink_node(3).unwrap();
+    let values: Vec<&str> = tree.children_content(1).map(|content| content.get_val()).collect();
+    assert_eq!(values, vec!("E"));
+    assert_eq!(tree.children_content(7).count(), 0);
+    assert_eq!(tree.children_content(3).count(), 0);
+    assert_eq!(tree.children_content(100).count(), 0);
+}
+
+#[test]
+fn check_in_dfs() {
+    fn in_dfs_values<T: NodeContent>(tree: &Tree<T>) -> Vec<String> {
+        tree.iterators().in_dfs().map(|(node, _)| String::from(node.get_content_ref().get_val())).collect()
+    }
+    // No children
+    let mut tree = <Tree>::new();
+    assert!(in_dfs_values(&tree).is_empty());
+    let _root = tree.set_root("root_node").unwrap();
+    assert_eq!(in_dfs_values(&tree), path(&["root_node"]));
+    // One child per node
+    let _child_1 = tree.link_node("child_1", _root).unwrap();
+    let _child_1_1 = tree.link_node("child_1_1", _child_1).unwrap();
+    assert_eq!(in_dfs_values(&tree), path(&["child_1_1", "child_1", "root_node"]));
+    // Two children per node
+    let mut tree = tree_sample();
+    assert_eq!(in_dfs_values(&tree), path(&["D", "B", "H", "E", "A", "F", "C", "G"]));
+    let indexes: Vec<usize> = tree.iterators().in_dfs().map(|(_, index)| index).collect();
+    assert_eq!(indexes, vec!(3, 1, 7, 4, 0, 5, 2, 6));
+    // Unlinked first child, the next linked one takes its place
+    tree.unlink_node(3).unwrap();
+    assert_eq!(in_dfs_values(&tree), path(&["H", "E", "B", "A", "F", "C", "G"]));
+    // Three children
+    let forest = forest_sample();
+    let tree = forest.get_tree("test_tree").unwrap();
+    assert_eq!(in_dfs_values(tree), path(&["child_1", "root_node", "child_2_1_1", "child_2_1", "child_2", "child_2_2", "child_3"]));
+}
+
+#[test]
+fn check_level_nodes() {
+    let mut tree = tree_sample();
+    assert_eq!(tree.level_nodes(1), vec!(0));
+    assert_eq!(tree.level_nodes(2), vec!(1, 2));
+    assert_eq!(tree.level_nodes(3), vec!(3, 4, 5, 6));
+    assert_eq!(tree.level_nodes(4), vec!(7));
+    assert!(tree.level_nodes(5).is_empty());
+    assert!(tree.level_nodes(0).is_empty());
+    tree.unlink_node(4).unwrap();
+    assert_eq!(tree.level_nodes(3), vec!(3, 5, 6));
+    assert!(tree.level_nodes(4).is_empty());
+}
+
+#[test]
+fn check_reroot() {
+    let mut tree = tree_sample();
+    tree.reroot(1).unwrap();
+    assert_eq!(tree.validate(), Ok(()));
+    // "B" is the new root, and "A" takes its index
+    assert_eq!(tree.get_node_content(0).unwrap().get_val(), "B");
+    assert_eq!(tree.get_node_content(1).unwrap().get_val(), "A");
+    assert_eq!(tree.parent(0), None);
+    assert_eq!(tree.parent(1), Some(0));
+    assert_eq!(tree.node_path(1), Some(path(&["B", "A"])));
+    assert_eq!(tree.node_path(6), Some(path(&["B", "A", "C", "G"])));
+    assert_eq!(tree.get_node(6).unwrap().get_level(), 4);
+    assert_eq!(tree.children_indexes(0), Some(vec!(3, 4, 1)));
+    assert_eq!(tree.find_node(&["B", "E", "H"]), Some(7));
+    // Reroot at a deeper node
+    let mut tree = tree_sample();
+    tree.reroot(7).unwrap();
+    assert_eq!(tree.validate(), Ok(()));
+    assert_eq!(tree.node_path(7), Some(path(&["H", "E", "B", "A"])));
+    assert_eq!(tree.find_node(&["H", "E", "B", "A", "C", "F"]), Some(5));
+    assert_eq!(tree.find_node(&["H", "E", "B", "D"]), Some(3));
+    assert_eq!(tree.subtree_size(0), Some(8));
+    // Root and unlinked nodes
+    let mut tree = tree_sample();
+    assert_eq!(tree.reroot(0), Ok(()));
+    assert_eq!(tree.node_path(7), Some(path(&["A", "B", "E", "H"])));
+    tree.unlink_node(4).unwrap();
+    assert_eq!(tree.reroot(7), Err(String::from("Node 7 is not linked")));
+    assert_eq!(tree.reroot(100), Err(String::from("Node 100 is not linked")));
+    // Names collide
+    let mut tree = <Tree>::new();
+    let _root = tree.set_root("X").unwrap();
+    let _child_1 = tree.link_node("Y", _root).unwrap();
+    let _child_1_1 = tree.link_node("X", _child_1).unwrap();
+    assert_eq!(tree.reroot(_child_1), Err(String::from("Node 1 already has a child named \"X\"")));
+    assert_eq!(tree.validate(), Ok(()));
+}
+
+#[test]
+fn check_from_events() {
+    let tree = tree_sample();
+    let events = tree.iterators().dfs_events().map(|event| match event {
+        DfsEvent::Enter(node, _) => BuildEvent::Open(node.get_content_ref().gen_content()),
+        DfsEvent::Leave(_, _) => BuildEvent::Close
+    });
+    let rebuilt = <Tree>::from_events(events).unwrap();
+    assert!(tree.diff(&rebuilt).is_empty());
+    assert_eq!(format!("{}", rebuilt), format!("{}", tree));
+    assert_eq!(<Tree>::from_events(vec!()).unwrap().get_nodes_len(), 0);
+    // Mismatched events
+    let open = |content: &str| BuildEvent::Open(String::from(content));
+    assert_eq!(<Tree>::from_events(vec!(open("A"), open("B"), BuildEvent::Close)).unwrap_err(), "1 nodes not closed");
+    assert_eq!(<Tree>::from_events(vec!(open("A"), BuildEvent::Close, BuildEvent::Close)).unwrap_err(), "Close without a matching open");
+    assert_eq!(<Tree>::from_events(vec!(open("A"), BuildEvent::Close, open("B"))).unwrap_err(), "Node \"B\" opened after closing the root node");
+    assert_eq!(Tree::<WeightNode>::from_events(vec!(open("0:A"), open("B"))).unwrap_err(), "Invalid node content \"B\"");
+}
+
+#[test]
+fn check_ancestors_of() {
+    let tree = tree_sample();
+    assert_eq!(tree.ancestors_of(7), Some(vec!(0, 1, 4, 7)));
+    assert_eq!(tree.ancestors_of(0), Some(vec!(0)));
+    assert_eq!(tree.ancestors_of(100), None);
+}
+
+#[test]
+fn check_descendants_count_map() {
+    let mut tree = tree_sample();
+    let sizes = tree.descendants_count_map();
+    assert_eq!(sizes, vec!(8, 4, 3, 1, 2, 1, 1, 1));
+    for node_index in [0, 1, 4, 7].iter() {
+        assert_eq!(Some(sizes[*node_index]), tree.subtree_size(*node_index));
+    }
+    tree.unlink_node(4).unwrap();
+    assert_eq!(tree.descendants_count_map(), vec!(6, 2, 3, 1, 0, 1, 1, 0));
+    assert!(<Tree>::new().descendants_count_map().is_empty());
+}
+
+#[test]
+fn check_update_node_transactional() {
+    let mut tree = tree_sample();
+    // "E" can't be renamed to "D", its sibling
+    assert_eq!(tree.update_node("D", 4), None);
+    assert_eq!(tree.get_node_content(4).unwrap().get_val(), "E");
+    assert_eq!(tree.find_node(&["A", "B", "D"]), Some(3));
+    assert_eq!(tree.find_node(&["A", "B", "E", "H"]), Some(7));
+    assert_eq!(tree.validate(), Ok(()));
+    // Same value in another branch is fine
+    assert_eq!(tree.update_node("F", 4), Some(4));
+    assert_eq!(tree.find_node(&["A", "B", "F", "H"]), Some(7));
+    assert_eq!(tree.update_node("F", 4), Some(4));
+    assert_eq!(tree.validate(), Ok(()));
+    // Child map uses the node value, not the raw content
+    let mut tree = Tree::<WeightNode>::new();
+    let _root = tree.set_root("0:root_node").unwrap();
+    let _child_1 = tree.link_node("10:child_1", _root).unwrap();
+    let _child_2 = tree.link_node("20:child_2", _root).unwrap();
+    assert_eq!(tree.update_node("child_3", _child_1), None);
+    assert_eq!(tree.update_node("30:child_2", _child_1), None);
+    assert_eq!(tree.get_node_content(_child_1).unwrap().get_weight(), 10);
+    assert_eq!(tree.update_node("30:child_3", _child_1), Some(_child_1));
+    assert_eq!(tree.find_node(&["root_node", "child_3"]), Some(_child_1));
+    assert_eq!(tree.validate(), Ok(()));
+}
+
+#[test]
+fn check_preorder_paths() {
+    let mut tree = tree_sample();
+    let paths: Vec<Vec<String>> = tree.iterators().preorder_paths().collect();
+    assert_eq!(paths.len(), 8);
+    assert_eq!(paths[0], path(&["A"]));
+    assert_eq!(paths[1], path(&["A", "B"]));
+    assert_eq!(paths[2], path(&["A", "B", "D"]));
+    assert_eq!(paths[4], path(&["A", "B", "E", "H"]));
+    assert_eq!(paths[5], path(&["A", "C"]));
+    assert_eq!(paths[7], path(&["A", "C", "G"]));
+    tree.unlink_node(1).unwrap();
+    let paths: Vec<String> = tree.iterators().preorder_paths().map(|p| p.join("/")).collect();
+    assert_eq!(paths, vec!("A", "A/C", "A/C/F", "A/C/G"));
+    assert_eq!(<Tree>::new().iterators().preorder_paths().count(), 0);
+}
+
+#[test]
+fn check_contains_path() {
+    let tree = tree_sample();
+    assert!(tree.contains_path(0, &["B", "E", "H"]));
+    assert!(tree.contains_path(2, &["G"]));
+    assert!(tree.contains_path(2, &[]));
+    assert!(!tree.contains_path(0, &["B", "F"]));
+    assert!(!tree.contains_path(100, &[]));
+}
+
+#[test]
+fn check_raw_node_conversions() {
+    let content = String::from("root_node");
+    let node = RawNode::from(content.clone());
+    assert_eq!(node.get_val(), "root_node");
+    assert_eq!(node.into_inner(), content);
+    let node: RawNode = "child_1".into();
+    assert_eq!(node.gen_content(), "child_1");
+}
+
+#[test]
+fn check_for_each_child_mut() {
+    #[derive(Debug)]
+    struct CounterNode {
+        content: String,
+        counter: usize
+    }
+
+    impl NodeContent for CounterNode {
+        fn new(content: &str) -> Option<Self> {
+            Some(Self { content: String::from(content), counter: 0 })
+        }
+
+        fn get_val(&self) -> &str {
+            &self.content
+        }
+
+        fn gen_content(&self) -> String {
+            String::from(self.get_val())
+        }
+    }
+
+    let mut tree = Tree::<CounterNode>::new();
+    let _root = tree.set_root("root_node").unwrap();
+    let _child_1 = tree.link_node("child_1", _root).unwrap();
+    let _child_2 = tree.link_node("child_2", _root).unwrap();
+    let _child_3 = tree.link_node("child_3", _root).unwrap();
+    let _child_2_1 = tree.link_node("child_2_1", _child_2).unwrap();
+    tree.unlink_node(_child_3).unwrap();
+    tree.for_each_child_mut(_root, |node| node.get_mut_content_ref().counter += 1);
+    tree.for_each_child_mut(_root, |node| node.get_mut_content_ref().counter += 1);
+    let counters: Vec<usize> = tree.iterators().sequential().map(|(node, _)| node.get_content_ref().counter).collect();
+    assert_eq!(counters, vec!(0, 2, 2, 0, 0));
+    // Nothing happens with invalid nodes
+    tree.for_each_child_mut(100, |node| node.get_mut_content_ref().counter += 1);
+}
+
+#[test]
+fn check_swap_nodes_content() {
+    let mut tree = tree_sample();
+    // Leaves in different branches
+    assert_eq!(tree.swap_nodes_content(3, 6), Ok(()));
+    assert_eq!(tree.find_node(&["A", "B", "G"]), Some(3));
+    assert_eq!(tree.find_node(&["A", "C", "D"]), Some(6));
+    assert_eq!(tree.find_node(&["A", "B", "D"]), None);
+    assert_eq!(tree.validate(), Ok(()));
+    // Siblings
+    assert_eq!(tree.swap_nodes_content(1, 2), Ok(()));
+    assert_eq!(tree.find_node(&["A", "C", "E", "H"]), Some(7));
+    assert_eq!(tree.find_node(&["A", "B", "F"]), Some(5));
+    assert_eq!(tree.validate(), Ok(()));
+    // Root keeps its children
+    assert_eq!(tree.swap_nodes_content(0, 7), Ok(()));
+    assert_eq!(tree.find_node(&["H", "C", "E", "A"]), Some(7));
+    assert_eq!(tree.validate(), Ok(()));
+    // Errors
+    let mut tree = tree_sample();
+    tree.update_node("D", 5).unwrap();
+    assert_eq!(tree.swap_nodes_content(3, 6), Err(String::from("Node 2 already has a child named \"D\"")));
+    assert_eq!(tree.find_node(&["A", "B", "D"]), Some(3));
+    assert_eq!(tree.swap_nodes_content(3, 100), Err(String::from("Node 3 or 100 doesn't exist")));
+    assert_eq!(tree.validate(), Ok(()));
+}
+
+#[test]
+fn check_count_by_level() {
+    let mut tree = tree_sample();
+    assert_eq!(tree.count_by_level(), vec!(1, 2, 4, 1));
+    tree.unlink_node(4).unwrap();
+    assert_eq!(tree.count_by_level(), vec!(1, 2, 3));
+    assert!(<Tree>::new().count_by_level().is_empty());
+}
+
+#[test]
+fn check_pending() {
+    let tree = tree_sample();
+    let mut iter = tree.iterators().bfs();
+    assert_eq!(iter.pending(), 1);
+    let mut pending = vec!();
+    while iter.next().is_some() {
+        pending.push(iter.pending());
+    }
+    assert_eq!(pending, vec!(2, 3, 4, 3, 3, 2, 1, 0));
+    let mut iter = tree.iterators().pre_dfs();
+    let mut pending = vec!(iter.pending());
+    while iter.next().is_some() {
+        pending.push(iter.pending());
+    }
+    assert_eq!(pending, vec!(1, 2, 3, 2, 2, 1, 2, 1, 0));
+    let mut iter = tree.iterators().post_dfs();
+    assert_eq!(iter.pending(), 1);
+    iter.next();
+    // After "D", "A" and "B" wait for their children, "C" and "E" wait to be expanded
+    assert_eq!(iter.pending(), 4);
+    assert_eq!(iter.count(), 7);
+}
+
+#[test]
+fn check_link_nodes() {
+    let mut tree = <Tree>::new();
+    let _root = tree.set_root("root_node").unwrap();
+    assert_eq!(tree.link_nodes(&["child_1", "child_2", "child_3"], _root), Ok(vec!(1, 2, 3)));
+    assert_eq!(tree.find_node(&["root_node", "child_3"]), Some(3));
+    // Nothing is linked on failure
+    assert_eq!(tree.link_nodes(&["child_4", "child_2"], _root), Err(String::from("Node 0 already has a child named \"child_2\"")));
+    assert_eq!(tree.link_nodes(&["child_4", "child_4"], _root), Err(String::from("Node 0 already has a child named \"child_4\"")));
+    assert_eq!(tree.link_nodes(&["child_1_1"], 100), Err(String::from("Parent node 100 is not linked")));
+    assert_eq!(tree.get_nodes_len(), 4);
+    let mut tree = Tree::<WeightNode>::with_max_children(2);
+    let _root = tree.set_root("0:root_node").unwrap();
+    assert_eq!(tree.link_nodes(&["1:child_1", "child_2"], _root), Err(String::from("Invalid node content \"child_2\"")));
+    assert_eq!(tree.link_nodes(&["1:child_1", "2:child_2", "3:child_3"], _root), Err(String::from("Node 0 can't have more children")));
+    assert_eq!(tree.get_nodes_len(), 1);
+    assert_eq!(tree.link_nodes(&["1:child_1", "2:child_2"], _root), Ok(vec!(1, 2)));
+}
+
+#[test]
+fn check_root_level() {
+    let mut tree = <Tree>::new_with_root_level(0);
+    let _root = tree.set_root("root_node").unwrap();
+    let _child_1 = tree.link_node("child_1", _root).unwrap();
+    let _child_1_1 = tree.link_node("child_1_1", _child_1).unwrap();
+    assert_eq!(tree.get_node(_root).unwrap().get_level(), 0);
+    assert_eq!(tree.get_node(_child_1).unwrap().get_level(), 1);
+    assert_eq!(tree.get_node(_child_1_1).unwrap().get_level(), 2);
+    assert_eq!(tree.level_nodes(1), vec!(_child_1));
+    assert_eq!(tree.count_by_level(), vec!(1, 1, 1));
+    assert_eq!(format!("{}", tree), "root_node\n  child_1\n    child_1_1\n");
+    assert_eq!(tree.validate(), Ok(()));
+    // Default is 1
+    assert_eq!(tree_sample().get_node(0).unwrap().get_level(), 1);
+}
+
+#[test]
+fn check_map_in_place() {
+    let mut tree = tree_sample();
+    tree.map_in_place(|content| *content = RawNode::from(content.get_val().to_lowercase())).unwrap();
+    assert_eq!(tree.find_node(&["a", "b", "e", "h"]), Some(7));
+    assert_eq!(tree.find_node(&["a", "c", "g"]), Some(6));
+    assert_eq!(tree.find_node(&["A", "C", "G"]), None);
+    assert_eq!(tree.validate(), Ok(()));
+    // Siblings exchanging values
+    let mut tree = tree_sample();
+    tree.map_in_place(|content| {
+        let new_val = match content.get_val() { "B" => "C", "C" => "B", val => val };
+        *content = RawNode::from(new_val);
+    }).unwrap();
+    assert_eq!(tree.find_node(&["A", "C", "E", "H"]), Some(7));
+    assert_eq!(tree.find_node(&["A", "B", "F"]), Some(5));
+    assert_eq!(tree.validate(), Ok(()));
+    // Siblings ending up with the same key
+    let mut tree = tree_sample();
+    let result = tree.map_in_place(|content| {
+        if content.get_val() == "E" {
+            *content = RawNode::from("D");
+        }
+    });
+    assert_eq!(result, Err(String::from("Node 1 already has a child named \"D\"")));
+    assert!(tree.validate().is_err());
+}
+
+#[test]
+fn check_traverse() {
+    fn indexes<'a, I: Iterator<Item = (&'a Node, usize)>>(iter: I) -> Vec<usize> {
+        iter.map(|(_, index)| index).collect()
+    }
+    let mut tree = tree_sample();
+    tree.unlink_node(3).unwrap();
+    let iters = tree.iterators();
+    assert_eq!(indexes(iters.traverse(TraversalOrder::Bfs)), indexes(iters.bfs()));
+    assert_eq!(indexes(iters.traverse(TraversalOrder::InvBfs)), indexes(iters.inv_bfs()));
+    assert_eq!(indexes(iters.traverse(TraversalOrder::PreDfs)), indexes(iters.pre_dfs()));
+    assert_eq!(indexes(iters.traverse(TraversalOrder::InvPreDfs)), indexes(iters.inv_pre_dfs()));
+    assert_eq!(indexes(iters.traverse(TraversalOrder::PostDfs)), indexes(iters.post_dfs()));
+    assert_eq!(indexes(iters.traverse(TraversalOrder::InvPostDfs)), indexes(iters.inv_post_dfs()));
+    assert_eq!(indexes(iters.traverse(TraversalOrder::InDfs)), indexes(iters.in_dfs()));
+    assert_eq!(indexes(iters.traverse(TraversalOrder::Sequential)), indexes(iters.sequential()));
+    assert_eq!(indexes(iters.traverse(TraversalOrder::InvSequential)), indexes(iters.inv_sequential()));
+    assert_eq!(indexes(iters.traverse(TraversalOrder::Sequential)), vec!(0, 1, 2, 3, 4, 5, 6, 7));
+    // Unlinked node D is skipped, the rest of the nodes are reached
+    assert_eq!(indexes(iters.traverse(TraversalOrder::Bfs)), vec!(0, 1, 2, 4, 5, 6, 7));
+    assert_eq!(indexes(iters.traverse(TraversalOrder::InvBfs)), vec!(0, 2, 1, 6, 5, 4, 7));
+    assert_eq!(indexes(iters.traverse(TraversalOrder::PreDfs)), vec!(0, 1, 4, 7, 2, 5, 6));
+    assert_eq!(indexes(iters.traverse(TraversalOrder::InvPreDfs)), vec!(0, 2, 6, 5, 1, 4, 7));
+    assert_eq!(indexes(iters.traverse(TraversalOrder::PostDfs)), vec!(7, 4, 1, 5, 6, 2, 0));
+    assert_eq!(indexes(iters.traverse(TraversalOrder::InvPostDfs)), vec!(6, 5, 2, 7, 4, 1, 0));
+    assert_eq!(indexes(iters.traverse(TraversalOrder::InDfs)), vec!(7, 4, 1, 0, 5, 2, 6));
+}
+
+#[test]
+fn check_path_index() {
+    let mut tree = tree_sample();
+    let paths = tree.path_index('/');
+    assert_eq!(paths.len(), 8);
+    assert_eq!(paths.get("A/B/E/H"), Some(&7));
+    assert_eq!(paths.get("A"), Some(&0));
+    assert_eq!(paths.get("A/C/F"), Some(&5));
+    tree.unlink_node(2).unwrap();
+    let paths = tree.path_index('.');
+    assert_eq!(paths.len(), 5);
+    assert_eq!(paths.get("A.B.D"), Some(&3));
+    assert_eq!(paths.get("A.C"), None);
+    assert!(<Tree>::new().path_index('/').is_empty());
+}
+
+#[test]
+fn check_remove_and_compact() {
+    let mut tree = tree_sample();
+    let _i = tree.link_node("I", 6).unwrap();
+    let _j = tree.link_node("J", _i).unwrap();
+    assert_eq!(tree.get_nodes_len(), 10);
+    // Last added subtree, "I" with "J"
+    assert_eq!(tree.remove_and_compact(_i), Ok(()));
+    assert_eq!(tree.get_nodes_len(), 8);
+    assert_eq!(tree.find_node(&["A", "C", "G", "I"]), None);
+    assert_eq!(tree.validate(), Ok(()));
+    // Slots can be reused
+    assert_eq!(tree.link_node("I", 6), Some(8));
+    assert_eq!(tree.find_node(&["A", "C", "G", "I"]), Some(8));
+    // Not at the end, just unlinked
+    assert_eq!(tree.remove_and_compact(1), Ok(()));
+    assert_eq!(tree.get_nodes_len(), 9);
+    assert_eq!(tree.dead_nodes(), vec!(1, 3, 4, 7));
+    assert_eq!(tree.remove_and_compact(1), Err(String::from("Node 1 is not a linked child")));
+    assert_eq!(tree.remove_and_compact(0), Err(String::from("Node 0 is not a linked child")));
+}
+
+#[test]
+fn check_is_leaf_is_root() {
+    let mut tree = tree_sample();
+    let root = tree.get_node(0).unwrap();
+    assert!(root.is_root());
+    assert!(!root.is_leaf());
+    let leaf = tree.get_node(7).unwrap();
+    assert!(leaf.is_leaf());
+    assert!(!leaf.is_root());
+    // Only unlinked children
+    tree.unlink_node(7).unwrap();
+    assert!(tree.get_node(4).unwrap().is_leaf());
+}
+
+#[test]
+fn check_map_trees() {
+    let mut tree = <Tree>::new();
+    let _root = tree.set_root("0:root_node").unwrap();
+    let _child_1 = tree.link_node("10:child_1", _root).unwrap();
+    let _child_2 = tree.link_node("20:child_2", _root).unwrap();
+    let _child_2_1 = tree.link_node("21:child_2_1", _child_2).unwrap();
+    tree.unlink_node(_child_1).unwrap();
+    let mut forest = <Forest>::new();
+    forest.add_tree("test_tree", tree);
+    let weights = forest.map_trees::<WeightNode>().unwrap();
+    let tree = weights.get_tree("test_tree").unwrap();
+    assert_eq!(tree.get_nodes_len(), 4);
+    assert_eq!(tree.find_node(&["root_node", "child_2", "child_2_1"]), Some(3));
+    assert_eq!(tree.get_node_content(3).unwrap().get_weight(), 21);
+    assert_eq!(tree.find_node(&["root_node", "child_1"]), None);
+    assert_eq!(tree.validate(), Ok(()));
+    // Contents that can't be parsed
+    forest.add_tree("other_tree", tree_sample());
+    assert_eq!(forest.map_trees::<WeightNode>().unwrap_err(), "other_tree");
+    // Back to raw nodes
+    let raw = weights.map_trees::<RawNode>().unwrap();
+    assert_eq!(raw.get_tree("test_tree").unwrap().find_node(&["0:root_node", "20:child_2"]), Some(2));
+}
+
+#[test]
+fn check_bfs_collect() {
+    let tree = tree_sample();
+    let collected = tree.bfs_collect();
+    assert_eq!(collected.len(), 8);
+    for ((content, index, parent), (node, node_index)) in collected.iter().zip(tree.iterators().bfs()) {
+        assert_eq!(content, &node.get_content_ref().gen_content());
+        assert_eq!(*index, node_index);
+        assert_eq!(*parent, node.get_parent_position());
+    }
+    assert_eq!(collected[7], (String::from("H"), 7, Some(4)));
+    // Snapshot can be moved to another thread
+    let handle = std::thread::spawn(move || collected.len());
+    assert_eq!(handle.join().unwrap(), 8);
+}
+
+#[test]
+fn check_relink_node() {
+    let mut tree = tree_sample();
+    // Move "E" under "C"
+    assert_eq!(tree.relink_node(4, 2), Ok(()));
+    assert_eq!(tree.find_node(&["A", "C", "E", "H"]), Some(7));
+    assert_eq!(tree.find_node(&["A", "B", "E"]), None);
+    assert_eq!(tree.validate(), Ok(()));
+    // Move "E" under "G", one level deeper
+    assert_eq!(tree.relink_node(4, 6), Ok(()));
+    assert_eq!(tree.get_node(4).unwrap().get_level(), 4);
+    assert_eq!(tree.get_node(7).unwrap().get_level(), 5);
+    assert_eq!(tree.validate(), Ok(()));
+    // Re-attach an unlinked subtree
+    tree.unlink_node(2).unwrap();
+    assert!(!tree.is_linked(7));
+    assert_eq!(tree.relink_node(2, 3), Ok(()));
+    assert!(tree.is_linked(7));
+    assert_eq!(tree.node_path(7), Some(path(&["A", "B", "D", "C", "G", "E", "H"])));
+    assert_eq!(tree.get_node(2).unwrap().get_level(), 4);
+    assert_eq!(tree.get_node(7).unwrap().get_level(), 7);
+    assert_eq!(tree.validate(), Ok(()));
+}
+
+#[test]
+fn check_relink_node_errors() {
+    let mut tree = tree_sample();
+    // Cycles
+    assert_eq!(tree.relink_node(1, 7), Err(String::from("Node 1 can't be linked to its own descendant 7")));
+    assert_eq!(tree.relink_node(1, 1), Err(String::from("Node 1 can't be linked to its own descendant 1")));
+    assert_eq!(tree.relink_node(0, 1), Err(String::from("Node 0 can't be relinked")));
+    // Unlinked parent
+    tree.unlink_node(4).unwrap();
+    assert_eq!(tree.relink_node(3, 7), Err(String::from("Parent node 7 is not linked")));
+    // Name collision
+    tree.update_node("D", 5).unwrap();
+    assert_eq!(tree.relink_node(3, 2), Err(String::from("Node 2 already has a child named \"D\"")));
+    // Nothing changed
+    assert_eq!(tree.find_node(&["A", "B", "D"]), Some(3));
+    assert_eq!(tree.dead_nodes(), vec!(4, 7));
+    assert_eq!(tree.validate(), Ok(()));
+}
+
+#[test]
+fn check_nth_child() {
+    let mut forest = forest_sample();
+    let mut tree = forest.remove_tree("test_tree").unwrap();
+    assert_eq!(tree.nth_child(0, 0), Some(1));
+    assert_eq!(tree.nth_child(0, 2), Some(6));
+    tree.unlink_node(1).unwrap();
+    assert_eq!(tree.nth_child(0, 0), Some(2));
+    assert_eq!(tree.nth_child(0, 1), Some(6));
+    assert_eq!(tree.nth_child(0, 2), None);
+    assert_eq!(tree.nth_child(100, 0), None);
+}
+
+#[test]
+fn check_siblings_navigation() {
+    let mut forest = forest_sample();
+    let mut tree = forest.remove_tree("test_tree").unwrap();
+    assert_eq!(tree.next_sibling(1), Some(2));
+    assert_eq!(tree.next_sibling(2), Some(6));
+    assert_eq!(tree.next_sibling(6), None);
+    assert_eq!(tree.prev_sibling(6), Some(2));
+    assert_eq!(tree.prev_sibling(1), None);
+    assert_eq!(tree.next_sibling(0), None);
+    assert_eq!(tree.prev_sibling(0), None);
+    // Skip holes
+    tree.unlink_node(2).unwrap();
+    assert_eq!(tree.next_sibling(1), Some(6));
+    assert_eq!(tree.prev_sibling(6), Some(1));
+    assert_eq!(tree.next_sibling(2), None);
+    assert_eq!(tree.next_sibling(100), None);
+}
+
+#[test]
+fn check_forest_shrink_to_fit() {
+    let mut forest = <Forest>::with_capacity(100);
+    for i in 0..100 {
+        forest.new_tree(&format!("tree_{}", i));
+    }
+    assert!(forest.capacity() >= 100);
+    for i in 0..100 {
+        forest.remove_tree(&format!("tree_{}", i)).unwrap();
+    }
+    forest.shrink_to_fit();
+    assert!(forest.capacity() < 100);
+}
+
+#[test]
+fn check_levels() {
+    let mut tree = tree_sample();
+    let levels = tree.levels();
+    assert_eq!(levels.len(), 4);
+    assert_eq!(levels[2], vec!(3, 4, 5, 6));
+    assert_eq!(levels, vec!(vec!(0), vec!(1, 2), vec!(3, 4, 5, 6), vec!(7)));
+    tree.unlink_node(1).unwrap();
+    assert_eq!(tree.levels(), vec!(vec!(0), vec!(2), vec!(5, 6)));
+    assert!(<Tree>::new().levels().is_empty());
+}
+
+#[test]
+fn check_apply_edits() {
+    let mut tree = tree_sample();
+    let mut other = tree_sample();
+    other.unlink_node(4).unwrap();
+    other.update_node("X", 2).unwrap();
+    let _i = other.link_node("I", 3).unwrap();
+    let _j = other.link_node("J", _i).unwrap();
+    let edits = tree.diff(&other);
+    assert_eq!(tree.apply_edits(&edits), Ok(()));
+    assert!(tree.diff(&other).is_empty());
+    assert_eq!(tree.find_node(&["A", "B", "D", "I", "J"]), Some(12));
+    assert_eq!(tree.find_node(&["A", "X", "G"]), Some(10));
+    assert_eq!(tree.validate(), Ok(()));
+    // Content changes
+    let mut tree = Tree::<WeightNode>::new();
+    let _root = tree.set_root("0:root_node").unwrap();
+    let _child_1 = tree.link_node("10:child_1", _root).unwrap();
+    let mut other = Tree::<WeightNode>::new();
+    let _root = other.set_root("0:root_node").unwrap();
+    let _child_1 = other.link_node("20:child_1", _root).unwrap();
+    assert_eq!(tree.apply_edits(&tree.diff(&other)), Ok(()));
+    assert_eq!(tree.get_node_content(_child_1).unwrap().get_weight(), 20);
+    // Replace the whole tree
+    let mut tree = tree_sample();
+    let mut other = <Tree>::new();
+    let _root = other.set_root("Z").unwrap();
+    assert_eq!(tree.apply_edits(&tree.diff(&other)), Ok(()));
+    assert_eq!(tree.get_nodes_len(), 1);
+    assert!(tree.diff(&other).is_empty());
+    // Missing paths
+    assert_eq!(tree.apply_edits(&[TreeEdit::Removed(path(&["Z", "Y"]))]), Err(String::from("Can't remove node [\"Z\", \"Y\"]")));
+    assert_eq!(tree.apply_edits(&[added(&["A", "B"])]), Err(String::from("Can't add node [\"A\", \"B\"]")));
+}
+
+#[test]
+fn check_node_key() {
+    // Content in the format "id|Human text"
+    #[derive(Debug)]
+    struct LabelNode {
+        id: String,
+        label: String
+    }
+
+    impl NodeContent for LabelNode {
+        fn new(content: &str) -> Option<Self> {
+            let vec: Vec<&str> = content.split('|').collect();
+            if vec.len() == 2 {
+                Some(Self { id: String::from(vec[0]), label: String::from(vec[1]) })
+            }
+            else {
+                None
+            }
+        }
+
+        fn get_val(&self) -> &str {
+            &self.label
+        }
+
+        fn key(&self) -> &str {
+            &self.id
+        }
+
+        fn gen_content(&self) -> String {
+            format!("{}|{}", self.id, self.label)
+        }
+    }
+
+    let mut tree = Tree::<LabelNode>::new();
+    let _root = tree.set_root("root|The Root").unwrap();
+    let _child_1 = tree.link_node("child_1|First child", _root).unwrap();
+    let _child_2 = tree.link_node("child_2|First child", _root).unwrap();
+    assert_eq!(tree.find_node(&["root", "child_2"]), Some(_child_2));
+    // Labels are not unique, they are not used to find nodes
+    assert_eq!(tree.find_node(&["root", "First child"]), None);
+    assert_eq!(tree.node_path(_child_2), Some(path(&["root", "child_2"])));
+    assert_eq!(tree.link_nodes(&["child_1|Another child"], _root), Err(String::from("Node 0 already has a child named \"child_1\"")));
+    // Display uses the value
+    assert_eq!(format!("{}", tree), "The Root\n  First child\n  First child\n");
+    assert_eq!(tree.validate(), Ok(()));
+}
+
+#[test]
+fn check_count_matching() {
+    let mut tree = <Tree>::new();
+    let _root_node = tree.set_root("root_node").unwrap();
+    let _child_1 = tree.link_node("child_1", _root_node).unwrap();
+    let _child_2 = tree.link_node("child_2", _root_node).unwrap();
+    let _child_3 = tree.link_node("child_3", _root_node).unwrap();
+    let _child_1_1 = tree.link_node("child_1_1", _child_1).unwrap();
+    let _child_1_2 = tree.link_node("child_1_2", _child_1).unwrap();
+    let _child_2_1 = tree.link_node("child_2_1", _child_2).unwrap();
+    assert_eq!(tree.count_matching(|content| content.get_val().len() > 5), 7);
+    assert_eq!(tree.count_matching(|content| content.get_val().len() > 7), 4);
+    tree.unlink_node(_child_1).unwrap();
+    assert_eq!(tree.count_matching(|content| content.get_val().len() > 7), 2);
+    assert_eq!(<Tree>::new().count_matching(|_| true), 0);
+}
+
+#[test]
+fn check_root_content() {
+    assert!(<Tree>::new().root_content().is_none());
+    assert_eq!(tree_sample().root_content().unwrap().get_val(), "A");
+}
+
+#[test]
+fn check_child_pairs() {
+    let mut forest = forest_sample();
+    let mut tree = forest.remove_tree("test_tree").unwrap();
+    let pairs: Vec<(usize, usize)> = tree.child_pairs(0).collect();
+    assert_eq!(pairs, vec!((1, 2), (2, 6)));
+    tree.unlink_node(2).unwrap();
+    let pairs: Vec<(usize, usize)> = tree.child_pairs(0).collect();
+    assert_eq!(pairs, vec!((1, 6)));
+    assert_eq!(tree.child_pairs(3).count(), 0);
+    assert_eq!(tree.child_pairs(1).count(), 0);
+    assert_eq!(tree.child_pairs(100).count(), 0);
+}
+
+#[test]
+fn check_prune_to_depth() {
+    let mut tree = tree_sample();
+    tree.prune_to_depth(3);
+    assert_eq!(tree.subtree_size(0), Some(8));
+    tree.prune_to_depth(1);
+    assert_eq!(tree.levels(), vec!(vec!(0), vec!(1, 2)));
+    assert_eq!(tree.dead_nodes(), vec!(3, 4, 5, 6, 7));
+    tree.prune_to_depth(0);
+    assert_eq!(tree.subtree_size(0), Some(1));
+}
+
+#[test]
+fn check_iterators_at() {
+    let mut tree = tree_sample();
+    let indexes: Vec<usize> = tree.iterators_at(1).pre_dfs().map(|(_, index)| index).collect();
+    assert_eq!(indexes, vec!(1, 3, 4, 7));
+    let indexes: Vec<usize> = tree.iterators_at(2).bfs().map(|(_, index)| index).collect();
+    assert_eq!(indexes, vec!(2, 5, 6));
+    let indexes: Vec<usize> = tree.iterators_at(1).post_dfs().map(|(_, index)| index).collect();
+    assert_eq!(indexes, vec!(3, 7, 4, 1));
+    let indexes: Vec<usize> = tree.iterators_at(1).children().map(|(_, index)| index).collect();
+    assert_eq!(indexes, vec!(3, 4));
+    let paths: Vec<Vec<String>> = tree.iterators_at(4).preorder_paths().collect();
+    assert_eq!(paths, vec!(path(&["E"]), path(&["E", "H"])));
+    // Invalid nodes start at root
+    assert_eq!(tree.iterators_at(100).pre_dfs().count(), 8);
+    tree.unlink_node(4).unwrap();
+    assert_eq!(tree.iterators_at(7).in_dfs().count(), 6);
+    // Checked version
+    assert_eq!(tree.try_iterators_at(1).unwrap().pre_dfs().count(), 2);
+    assert_eq!(tree.try_iterators_at(7).err(), Some(String::from("Node 7 is not linked")));
+    assert_eq!(tree.try_iterators_at(100).err(), Some(String::from("Node 100 doesn't exist")));
+}
+
+#[test]
+fn check_deepest_node() {
+    let mut tree = tree_sample();
+    assert_eq!(tree.deepest_node(), Some(7));
+    tree.unlink_node(4).unwrap();
+    assert_eq!(tree.deepest_node(), Some(3));
+    assert_eq!(<Tree>::new().deepest_node(), None);
+}
+
+#[test]
+fn check_forest_values() {
+    let mut forest = forest_sample();
+    forest.add_tree("other_tree", tree_sample());
+    assert_eq!(forest.values().map(|tree| tree.get_nodes_len()).sum::<usize>(), 15);
+    for tree in forest.values_mut() {
+        tree.link_node("new_child", 0).unwrap();
+    }
+    assert_eq!(forest.values().map(|tree| tree.get_nodes_len()).sum::<usize>(), 17);
+}
+
+#[test]
+fn check_find_path_ci() {
+    let forest = forest_sample();
+    let tree = forest.get_tree("test_tree").unwrap();
+    assert_eq!(tree.find_path_ci(0, &["CHILD_2", "Child_2_1"]), Some(3));
+    assert_eq!(tree.find_path_ci(0, &["CHILD_2", "Child_2_1", "child_2_1_1"]), Some(4));
+    assert_eq!(tree.find_path_ci(0, &[]), Some(0));
+    assert_eq!(tree.find_path_ci(0, &["CHILD_4"]), None);
+    assert_eq!(tree.find_path_ci(10, &[]), None);
+}
+
+#[test]
+fn check_move_children() {
+    let mut tree = tree_sample();
+    // Root's children can't be moved to a leaf, it is their descendant
+    assert!(tree.move_children(0, 6).is_err());
+    // Move children of B to leaf G
+    tree.move_children(1, 6).unwrap();
+    assert_eq!(tree.num_children(1), Some(0));
+    assert_eq!(tree.children_indexes(6), Some(vec!(3, 4)));
+    assert_eq!(tree.find_node(&["A", "C", "G", "E", "H"]), Some(7));
+    assert_eq!(tree.get_node(7).unwrap().get_level(), 5);
+    assert!(tree.validate().is_ok());
+    // Collision with an existing child
+    tree.link_node("D", 1).unwrap();
+    assert!(tree.move_children(1, 6).is_err());
+    assert_eq!(tree.num_children(6), Some(2));
+    // Collision between the moved children
+    let mut tree = tree_sample();
+    tree.link_node("X", 1).unwrap();
+    tree.link_node("X", 1).unwrap();
+    assert!(tree.move_children(1, 6).is_err());
+    assert_eq!(tree.children_indexes(1), Some(vec!(3, 4, 8, 9)));
+    assert_eq!(tree.num_children(6), Some(0));
+    assert_eq!(tree.parent(3), Some(1));
+}
+
+#[test]
+fn check_node_child_map() {
+    let forest = forest_sample();
+    let tree = forest.get_tree("test_tree").unwrap();
+    let child_map = tree.get_node(0).unwrap().get_child_map();
+    assert_eq!(child_map.len(), 3);
+    assert_eq!(child_map.get("child_1"), Some(&1));
+    assert_eq!(child_map.get("child_2"), Some(&2));
+    assert_eq!(child_map.get("child_3"), Some(&6));
+    assert!(tree.get_node(1).unwrap().get_child_map().is_empty());
+}
+
+#[test]
+fn check_total_path_length() {
+    let mut tree = tree_sample();
+    assert_eq!(tree.total_path_length(), 13);
+    tree.unlink_node(4);
+    assert_eq!(tree.total_path_length(), 8);
+    assert_eq!(<Tree>::new().total_path_length(), 0);
+}
+
+#[test]
+fn check_is_balanced() {
+    let mut tree = tree_sample();
+    assert!(tree.is_balanced());
+    assert!(<Tree>::new().is_balanced());
+    // Make the subtree of B two levels deeper than the subtree of C
+    let _h = tree.find_node(&["A", "B", "E", "H"]).unwrap();
+    tree.link_node("I", _h).unwrap();
+    assert!(!tree.is_balanced());
+    // Unlinked nodes don't count
+    tree.unlink_node(_h);
+    assert!(tree.is_balanced());
+    // A chain is not balanced, missing children have height 0
+    let mut chain = <Tree>::new();
+    let mut node_index = chain.set_root("A").unwrap();
+    node_index = chain.link_node("B", node_index).unwrap();
+    assert!(chain.is_balanced());
+    chain.link_node("C", node_index).unwrap();
+    assert!(!chain.is_balanced());
+}
+
+#[test]
+fn check_equals_shape() {
+    let tree = tree_sample();
+    let mut weight_tree = Tree::<WeightNode>::new();
+    let _a = weight_tree.set_root("1:a").unwrap();
+    let _b = weight_tree.link_node("2:b", _a).unwrap();
+    let _c = weight_tree.link_node("3:c", _a).unwrap();
+    let _d = weight_tree.link_node("4:d", _b).unwrap();
+    let _e = weight_tree.link_node("5:e", _b).unwrap();
+    let _f = weight_tree.link_node("6:f", _c).unwrap();
+    let _g = weight_tree.link_node("7:g", _c).unwrap();
+    let _h = weight_tree.link_node("8:h", _e).unwrap();
+    assert!(tree.equals_shape(&weight_tree));
+    assert!(weight_tree.equals_shape(&tree));
+    // Same number of nodes, different arrangement
+    weight_tree.relink_node(_h, _d).unwrap();
+    assert!(!tree.equals_shape(&weight_tree));
+    assert!(!tree.equals_shape(&<Tree>::new()));
+    assert!(<Tree>::new().equals_shape(&Tree::<WeightNode>::new()));
+}
+
+#[test]
+fn check_gen_string() {
+    let mut tree = Tree::<WeightNode>::new();
+    let _root = tree.set_root("0:root_node").unwrap();
+    let _child_1 = tree.link_node("10:child_1", _root).unwrap();
+    let _child_2 = tree.link_node("20:child_2", _root).unwrap();
+    let _child_2_1 = tree.link_node("21:child_2_1", _child_2).unwrap();
+    let text = tree.gen_string();
+    assert!(text.contains("10:child_1"));
+    assert_eq!(text, "0:root_node\n  10:child_1\n  20:child_2\n    21:child_2_1\n");
+    assert_eq!(tree_sample().gen_string(), tree_sample().to_string());
+}
+
+#[test]
+fn check_parse_string() {
+    let mut tree = Tree::<WeightNode>::new();
+    let _root = tree.set_root("0:root_node").unwrap();
+    let _child_1 = tree.link_node("10:child_1", _root).unwrap();
+    let _child_2 = tree.link_node("20:child_2", _root).unwrap();
+    let _child_2_1 = tree.link_node("21:child_2_1", _child_2).unwrap();
+    let _child_3 = tree.link_node("30:child_3", _root).unwrap();
+    let parsed_tree = Tree::<WeightNode>::parse_string(&tree.gen_string()).unwrap();
+    assert!(tree.diff(&parsed_tree).is_empty());
+    assert_eq!(parsed_tree.gen_string(), tree.gen_string());
+    let child_2_1 = parsed_tree.find_node(&["root_node", "child_2", "child_2_1"]).unwrap();
+    assert_eq!(parsed_tree.get_node_content(child_2_1).unwrap().get_weight(), 21);
+    // Content with leading spaces or backslashes is escaped
+    let mut tree = <Tree>::new();
+    let _root = tree.set_root("root_node").unwrap();
+    let _child_1 = tree.link_node("  child_1", _root).unwrap();
+    let _child_1_1 = tree.link_node("\\child_1_1", _child_1).unwrap();
+    let _child_2 = tree.link_node(" ", _root).unwrap();
+    assert_eq!(tree.gen_string(), "root_node\n  \\  child_1\n    \\\\child_1_1\n  \\ \n");
+    let parsed_tree = <Tree>::parse_string(&tree.gen_string()).unwrap();
+    assert!(tree.diff(&parsed_tree).is_empty());
+    assert_eq!(parsed_tree.find_node(&["root_node", "  child_1", "\\child_1_1"]), Some(2));
+    assert_eq!(parsed_tree.find_node(&["root_node", " "]), Some(3));
+    // Errors
+    assert_eq!(Tree::<WeightNode>::parse_string("0:root\n  child").unwrap_err(), "Invalid node content \"child\"");
+    assert!(Tree::<WeightNode>::parse_string("0:root\n    1:child").is_err());
+    assert!(Tree::<WeightNode>::parse_string("0:root\n 1:child").is_err());
+    assert!(Tree::<WeightNode>::parse_string("0:root\n1:other_root").is_err());
+    assert_eq!(<Tree>::parse_string("").unwrap().get_nodes_len(), 0);
+}
+
+#[test]
+fn check_for_each_ancestor_mut() {
+    let mut tree = Tree::<WeightNode>::new();
+    let _a = tree.set_root("0:A").unwrap();
+    let _b = tree.link_node("0:B", _a).unwrap();
+    let _c = tree.link_node("0:C", _a).unwrap();
+    let _e = tree.link_node("0:E", _b).unwrap();
+    let _h = tree.link_node("0:H", _e).unwrap();
+    tree.for_each_ancestor_mut(_h, |node| node.get_mut_content_ref().weight += 1);
+    let weights: Vec<u32> = tree.get_nodes_ref().iter().map(|node| node.get_content_ref().get_weight()).collect();
+    assert_eq!(weights, vec!(1, 1, 0, 1, 0));
+    // Root has no ancestors
+    tree.for_each_ancestor_mut(_a, |node| node.get_mut_content_ref().weight += 1);
+    assert_eq!(tree.get_node_content(_a).unwrap().get_weight(), 1);
+}
+
+#[test]
+fn check_tree_with_root() {
+    let tree = <Tree>::with_root("root_node").unwrap();
+    assert_eq!(tree.get_nodes_len(), 1);
+    assert_eq!(tree.root_content().unwrap().get_val(), "root_node");
+    assert!(Tree::<WeightNode>::with_root("root_node").is_err());
+    assert_eq!(Tree::<WeightNode>::with_root("5:root_node").unwrap().root_content().unwrap().get_weight(), 5);
+}
+
+#[test]
+fn check_detach_subtree() {
+    let mut tree = tree_sample();
+    let _b = tree.find_node(&["A", "B"]).unwrap();
+    let subtree = tree.detach_subtree(_b).unwrap();
+    assert_eq!(subtree.root_content().unwrap().get_val(), "B");
+    assert_eq!(subtree.get_nodes_len(), 4);
+    assert_eq!(subtree.find_node(&["B", "E", "H"]), Some(3));
+    assert_eq!(subtree.get_node(3).unwrap().get_level(), 3);
+    assert!(subtree.validate().is_ok());
+    assert_eq!(tree.find_node(&["A", "B"]), None);
+    assert!(!tree.is_linked(_b));
+    assert_eq!(tree.subtree_size(0), Some(4));
+    // Errors
+    assert!(tree.detach_subtree(0).is_err());
+    assert!(tree.detach_subtree(_b).is_err());
+}
+
+#[test]
+fn check_post_dfs_peek_is_revisit() {
+    let mut tree = <Tree>::new();
+    let _a = tree.set_root("A").unwrap();
+    let _b = tree.link_node("B", _a).unwrap();
+    let _c = tree.link_node("C", _a).unwrap();
+    let mut iter = PostDfsIter::new(&tree);
+    assert_eq!(iter.peek_is_revisit(), Some(false));
+    assert_eq!(iter.next().unwrap().1, _b);
+    assert_eq!(iter.peek_is_revisit(), Some(false));
+    assert_eq!(iter.next().unwrap().1, _c);
+    // Children of A already returned
+    assert_eq!(iter.peek_is_revisit(), Some(true));
+    assert_eq!(iter.next().unwrap().1, _a);
+    assert_eq!(iter.peek_is_revisit(), None);
+    assert!(iter.next().is_none());
+    let mut iter = InvPostDfsIter::new(&tree);
+    assert_eq!(iter.next().unwrap().1, _c);
+    assert_eq!(iter.next().unwrap().1, _b);
+    assert_eq!(iter.peek_is_revisit(), Some(true));
+}
+
+#[test]
+fn check_compact_children() {
+    let mut tree = <Tree>::new();
+    let _root = tree.set_root("root_node").unwrap();
+    let children = tree.link_nodes(&["a", "b", "c", "d"], _root).unwrap();
+    tree.unlink_node(children[0]);
+    tree.unlink_node(children[2]);
+    assert_eq!(tree.get_node(_root).unwrap().get_num_children(), 4);
+    tree.compact_children(_root);
+    assert_eq!(tree.get_node(_root).unwrap().get_num_children(), 2);
+    assert_eq!(tree.children_indexes(_root), Some(vec!(children[1], children[3])));
+    assert_eq!(tree.get_node(children[1]).unwrap().get_parents_children_pos(), Some(0));
+    assert_eq!(tree.get_node(children[3]).unwrap().get_parents_children_pos(), Some(1));
+    assert!(tree.is_linked(children[3]));
+    assert_eq!(tree.find_node(&["root_node", "d"]), Some(children[3]));
+    assert!(tree.validate().is_ok());
+}
+
+#[test]
+fn check_find_first() {
+    let mut forest = forest_sample();
+    let tree = forest.get_mut_tree("test_tree").unwrap();
+    assert_eq!(tree.find_first(TraversalOrder::PreDfs, |content| content.get_val() == "child_2_1"), Some(3));
+    assert_eq!(tree.find_first(TraversalOrder::PreDfs, |content| content.get_val() == "child_4"), None);
+    // First node with a long name in each order
+    assert_eq!(tree.find_first(TraversalOrder::PreDfs, |content| content.get_val().len() > 7), Some(0));
+    assert_eq!(tree.find_first(TraversalOrder::PostDfs, |content| content.get_val().len() > 7), Some(4));
+    tree.link_node("child_3_1", 6).unwrap();
+    assert_eq!(tree.find_first(TraversalOrder::InvBfs, |content| content.get_val().starts_with("child_") && content.get_val().len() == 9), Some(7));
+}
+
+#[test]
+fn check_default() {
+    #[derive(Default)]
+    struct Document {
+        tree: Tree,
+        forest: Forest<WeightNode>
+    }
+    let mut document = Document::default();
+    assert_eq!(document.tree.get_nodes_len(), 0);
+    assert_eq!(document.forest.iter().count(), 0);
+    document.tree.set_root("root_node").unwrap();
+    assert_eq!(document.tree.find_node(&["root_node"]), Some(0));
+    let tree: Tree<WeightNode> = Tree::default();
+    assert!(tree.root_content().is_none());
+}
+
+#[test]
+fn check_children_sorted_by() {
+    let forest = forest_sample();
+    let tree = forest.get_tree("test_tree").unwrap();
+    let sorted: Vec<usize> = tree.children_sorted_by(0, |a, b| b.get_val().cmp(a.get_val())).map(|(_, node_index)| node_index).collect();
+    assert_eq!(sorted, vec!(6, 2, 1));
+    // Tree order is not modified
+    assert_eq!(tree.children_indexes(0), Some(vec!(1, 2, 6)));
+    assert_eq!(tree.children_sorted_by(1, |a, b| a.get_val().cmp(b.get_val())).count(), 0);
+}
+
+#[test]
+fn check_cycle_guard() {
+    let mut tree = tree_sample();
+    let _b = tree.find_node(&["A", "B"]).unwrap();
+    let _h = tree.find_node(&["A", "B", "E", "H"]).unwrap();
+    let error = String::from("Node 1 can't be linked to its own descendant 7");
+    assert_eq!(tree.relink_node(_b, _h).unwrap_err(), error);
+    assert_eq!(tree.move_children(_b, _h).unwrap_err(), error);
+    assert_eq!(tree.relink_node(_b, _b).unwrap_err(), "Node 1 can't be linked to its own descendant 1");
+    // Tree is not modified
+    assert_eq!(tree.subtree_size(0), Some(8));
+    assert!(tree.validate().is_ok());
+}
+
+#[test]
+fn check_level_of_path() {
+    let tree = tree_sample();
+    assert_eq!(tree.level_of_path(0, &["B", "E", "H"]), Some(4));
+    assert_eq!(tree.level_of_path(0, &[]), Some(1));
+    assert_eq!(tree.level_of_path(2, &["G"]), Some(3));
+    assert_eq!(tree.level_of_path(0, &["B", "X"]), None);
+    assert_eq!(tree.level_of_path(20, &[]), None);
+}
+
+#[test]
+fn check_degree() {
+    let mut forest = forest_sample();
+    let tree = forest.get_mut_tree("test_tree").unwrap();
+    assert_eq!(tree.max_degree(), 3);
+    assert_eq!(tree.get_node(0).unwrap().degree(), 3);
+    assert_eq!(tree.get_node(2).unwrap().degree(), 2);
+    assert_eq!(tree.get_node(1).unwrap().degree(), 0);
+    tree.unlink_node(1);
+    assert_eq!(tree.get_node(0).unwrap().degree(), 2);
+    assert_eq!(tree.max_degree(), 2);
+    assert_eq!(<Tree>::new().max_degree(), 0);
+}
+
+#[test]
+fn check_to_adjacency_list() {
+    let mut tree = tree_sample();
+    let adjacency_list = tree.to_adjacency_list();
+    assert_eq!(adjacency_list.len(), tree.subtree_size(0).unwrap());
+    assert_eq!(adjacency_list[0], (0, vec!(1, 2)));
+    assert_eq!(adjacency_list[1], (1, vec!(3, 4)));
+    tree.unlink_node(4);
+    let adjacency_list = tree.to_adjacency_list();
+    assert_eq!(adjacency_list.len(), 6);
+    assert_eq!(adjacency_list[1], (1, vec!(3)));
+    assert!(adjacency_list.iter().all(|(node_index, _)| *node_index != 7));
+}
+
+#[test]
+fn check_from_adjacency_list() {
+    let tree = tree_sample();
+    let adjacency_list = tree.to_adjacency_list();
+    let edges: Vec<(usize, usize)> = adjacency_list.iter().flat_map(|(parent, children)| children.iter().map(move |child| (*parent, *child))).collect();
+    let contents: Vec<&str> = tree.get_nodes_ref().iter().map(|node| node.get_content_ref().get_val()).collect();
+    let new_tree = <Tree>::from_adjacency_list(&edges, &contents).unwrap();
+    assert!(tree.diff(&new_tree).is_empty());
+    assert!(tree.equals_shape(&new_tree));
+    // Sample tree nodes were added in BFS order, so indexes are the same
+    assert_eq!(new_tree.to_adjacency_list(), adjacency_list);
+    // Unlinked nodes are extra roots, indexes must be compacted first
+    let mut tree = tree_sample();
+    tree.unlink_node(4).unwrap();
+    let adjacency_list = tree.to_adjacency_list();
+    let edges: Vec<(usize, usize)> = adjacency_list.iter().flat_map(|(parent, children)| children.iter().map(move |child| (*parent, *child))).collect();
+    let contents: Vec<&str> = tree.get_nodes_ref().iter().map(|node| node.get_content_ref().get_val()).collect();
+    assert_eq!(<Tree>::from_adjacency_list(&edges, &contents).unwrap_err(), "More than one root node");
+    let mut positions = vec!(0; tree.get_nodes_len());
+    for (position, (node_index, _)) in adjacency_list.iter().enumerate() {
+        positions[*node_index] = position;
+    }
+    let edges: Vec<(usize, usize)> = edges.iter().map(|(parent, child)| (positions[*parent], positions[*child])).collect();
+    let contents: Vec<&str> = adjacency_list.iter().map(|(node_index, _)| contents[*node_index]).collect();
+    let new_tree = <Tree>::from_adjacency_list(&edges, &contents).unwrap();
+    assert!(tree.diff(&new_tree).is_empty());
+    assert!(tree.equals_shape(&new_tree));
+    assert_eq!(new_tree.get_nodes_len(), 6);
+    // Errors
+    assert_eq!(<Tree>::from_adjacency_list(&[(0, 1)], &["A", "B", "C"]).unwrap_err(), "More than one root node");
+    assert_eq!(<Tree>::from_adjacency_list(&[(0, 1), (1, 0)], &["A", "B"]).unwrap_err(), "No root node");
+    assert_eq!(<Tree>::from_adjacency_list(&[(1, 2), (2, 1)], &["A", "B", "C"]).unwrap_err(), "Edges contain a cycle");
+    assert_eq!(<Tree>::from_adjacency_list(&[(0, 1), (1, 2), (0, 2)], &["A", "B", "C"]).unwrap_err(), "Node 2 has more than one parent");
+    assert!(<Tree>::from_adjacency_list(&[(0, 5)], &["A", "B"]).is_err());
+    assert_eq!(<Tree>::from_adjacency_list(&[], &[]).unwrap().get_nodes_len(), 0);
+}
+
+#[test]
+fn check_relink_from_unlinked_subtree() {
+    let mut tree = tree_sample();
+    let _b = tree.find_node(&["A", "B"]).unwrap();
+    let _c = tree.find_node(&["A", "C"]).unwrap();
+    let _e = tree.find_node(&["A", "B", "E"]).unwrap();
+    tree.unlink_node(_b);
+    // Move E out of the unlinked subtree, and then relink B
+    tree.relink_node(_e, _c).unwrap();
+    tree.relink_node(_b, _c).unwrap();
+    assert!(tree.validate().is_ok());
+    assert_eq!(tree.children_indexes(_b), Some(vec!(3)));
+    assert_eq!(tree.get_node(_b).unwrap().get_child("E"), None);
+    assert_eq!(tree.find_node(&["A", "C", "E", "H"]), Some(7));
+    assert_eq!(tree.find_node(&["A", "C", "B", "D"]), Some(3));
+    assert_eq!(tree.find_node(&["A", "C", "B", "E"]), None);
+}
+
+#[test]
+fn check_swap_unlinked_nodes_content() {
+    let mut tree = tree_sample();
+    // Unlinked sibling
+    tree.unlink_node(3);
+    assert_eq!(tree.swap_nodes_content(3, 4).unwrap_err(), "Node 3 is not linked");
+    assert_eq!(tree.find_node(&["A", "B", "D"]), None);
+    assert_eq!(tree.find_node(&["A", "B", "E"]), Some(4));
+    // Unlinked node with a stale parent, it must not overwrite the child map of B
+    tree.link_node("D", 1).unwrap();
+    assert_eq!(tree.swap_nodes_content(6, 3).unwrap_err(), "Node 3 is not linked");
+    assert_eq!(tree.get_node_content(3).unwrap().get_val(), "D");
+    assert_eq!(tree.find_node(&["A", "B", "D"]), Some(8));
+    assert_eq!(tree.find_node(&["A", "C", "G"]), Some(6));
+    assert!(tree.validate().is_ok());
+    // Linked siblings, but the key of one of them is not in the child map
+    *tree.get_mut_nodes_ref()[8].get_mut_content_ref() = RawNode::from("X");
+    assert_eq!(tree.swap_nodes_content(8, 4).unwrap_err(), "Node 1 child map is out of sync");
+    assert_eq!(tree.get_node_content(4).unwrap().get_val(), "E");
+    assert_eq!(tree.find_node(&["A", "B", "E"]), Some(4));
+}
+
+#[test]
+fn check_apply_edits_added_custom_content() {
+    let mut tree = Tree::<WeightNode>::new();
+    let _root = tree.set_root("0:root").unwrap();
+    let mut other = Tree::<WeightNode>::new();
+    let _other_root = other.set_root("0:root").unwrap();
+    let _child = other.link_node("10:child", _other_root).unwrap();
+    let _grandchild = other.link_node("20:grandchild", _child).unwrap();
+    let edits = tree.diff(&other);
+    assert_eq!(edits[0], TreeEdit::Added { path: path(&["root", "child"]), content: String::from("10:child") });
+    assert_eq!(tree.apply_edits(&edits), Ok(()));
+    assert!(tree.diff(&other).is_empty());
+    let grandchild = tree.find_node(&["root", "child", "grandchild"]).unwrap();
+    assert_eq!(tree.get_node_content(grandchild).unwrap().get_weight(), 20);
+}
+
+#[test]
+fn check_iterators_skip_unlinked_children() {
+    let mut tree = tree_sample();
+    tree.unlink_node(1).unwrap();
+    assert_eq!(tree.find_first(TraversalOrder::Bfs, |content| content.get_val() == "C"), Some(2));
+    assert_eq!(tree.find_first(TraversalOrder::PreDfs, |content| content.get_val() == "G"), Some(6));
+    assert_eq!(tree.find_first(TraversalOrder::PostDfs, |content| content.get_val() == "B"), None);
+    assert_eq!(tree.iterators().traverse(TraversalOrder::Bfs).count(), 4);
+    assert_eq!(tree.zip_bfs(&tree).count(), 4);
+    assert_eq!(tree.iterators().inv_pre_dfs().map(|(_, index)| index).collect::<Vec<usize>>(), vec!(0, 2, 6, 5));
+    assert_eq!(tree.iterators().inv_post_dfs().map(|(_, index)| index).collect::<Vec<usize>>(), vec!(6, 5, 2, 0));
+}
+
+#[test]
+fn check_depth_with_root_level() {
+    let mut tree = <Tree>::new_with_root_level(0);
+    let _root = tree.set_root("root_node").unwrap();
+    let _child_1 = tree.link_node("child_1", _root).unwrap();
+    let _child_1_1 = tree.link_node("child_1_1", _child_1).unwrap();
+    let _child_2 = tree.link_node("child_2", _root).unwrap();
+    assert_eq!(tree.get_node(_root).unwrap().get_depth(), 0);
+    assert_eq!(tree.get_node(_child_1).unwrap().get_depth(), 1);
+    assert_eq!(tree.get_node(_child_1_1).unwrap().get_depth(), 2);
+    // Depth is recalculated when nodes are moved
+    tree.relink_node(_child_1_1, _child_2).unwrap();
+    assert_eq!(tree.get_node(_child_1_1).unwrap().get_depth(), 2);
+    tree.reroot(_child_2).unwrap();
+    assert_eq!(tree.get_node(0).unwrap().get_depth(), 0);
+    assert_eq!(tree.get_node(0).unwrap().get_level(), 0);
+    assert_eq!(tree.find_node(&["child_2", "child_1_1"]).map(|node_index| tree.get_node(node_index).unwrap().get_depth()), Some(1));
+    assert!(tree.validate().is_ok());
+    // Depth and level out of sync
+    let depth = tree.get_node(1).unwrap().get_depth();
+    tree.get_mut_nodes_ref()[1].set_depth(10);
+    assert!(tree.validate().is_err());
+    tree.get_mut_nodes_ref()[1].set_depth(depth);
+    assert!(tree.validate().is_ok());
+    for node in tree.get_mut_nodes_ref().iter_mut() {
+        node.set_level(node.get_level() + 1);
+    }
+    assert_eq!(tree.validate(), Err(String::from("Root node has level 1 but the root level is 0")));
+}
+
+#[test]
+fn check_update_unlinked_node() {
+    let mut tree = tree_sample();
+    let _d = tree.find_node(&["A", "B", "D"]).unwrap();
+    tree.unlink_node(_d).unwrap();
+    // E is a live sibling, but D doesn't belong to B anymore
+    assert_eq!(tree.update_node("E", _d), Some(_d));
+    assert_eq!(tree.get_node_content(_d).unwrap().get_val(), "E");
+    assert_eq!(tree.find_node(&["A", "B", "E"]), Some(4));
+    // A new live D is not affected by renaming the unlinked one
+    let _new_d = tree.link_node("D", 1).unwrap();
+    tree.update_node("D", _d).unwrap();
+    tree.update_node("X", _d).unwrap();
+    assert_eq!(tree.find_node(&["A", "B", "D"]), Some(_new_d));
+    assert!(tree.validate().is_ok());
+    // Nodes inside an unlinked subtree still update the child map of their parent
+    tree.unlink_node(4).unwrap();
+    assert_eq!(tree.update_node("D", 7), Some(7));
+    assert_eq!(tree.get_nodes_ref()[4].get_child("D"), Some(7));
+}
+
 //TODO: add check for all iterators
\ No newline at end of file
@@ -1,5 +1,12 @@
+#[cfg(feature = "std")]
+use std::collections::{HashMap as Map, VecDeque};
+#[cfg(not(feature = "std"))]
+use alloc::{collections::{BTreeMap as Map, VecDeque}, format, string::String, vec, vec::Vec};
 use crate::node::*;
 use crate::iter::*;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::Zip;
 
 //---- Structs ----//
 
@@ -7,7 +14,45 @@ use crate::iter::*;
 #[derive(Debug)]
 pub struct Tree<T: NodeContent = RawNode> {
     /// Tree nodes.
-    nodes: Vec<Node<T>>
+    nodes: Vec<Node<T>>,
+    /// Maximum number of children per node.
+    max_children: Option<usize>,
+    /// Level of the root node.
+    root_level: usize
+}
+
+/// Structural edit operation between two trees, generated by [`Tree::diff()`].
+/// 
+/// Nodes are identified by their path of keys, starting from root.
+#[derive(Debug, PartialEq, Clone)]
+pub enum TreeEdit {
+    /// Node added.
+    Added {
+        /// Node path.
+        path: Vec<String>,
+        /// Generated content.
+        content: String
+    },
+    /// Node removed, along with its subtree.
+    Removed(Vec<String>),
+    /// Node with the same key but different generated content.
+    ContentChanged {
+        /// Node path.
+        path: Vec<String>,
+        /// Old generated content.
+        old: String,
+        /// New generated content.
+        new: String
+    }
+}
+
+/// Event used to build a tree with [`Tree::from_events()`].
+#[derive(Debug, PartialEq, Clone)]
+pub enum BuildEvent {
+    /// Open a new node, with its content, as child of the currently open node.
+    Open(String),
+    /// Close the currently open node.
+    Close
 }
 
 //---- Implementations ----//
@@ -17,7 +62,205 @@ impl<T: NodeContent> Tree<T> {
     pub fn new() -> Self {
         Self {
             nodes: vec!(),
+            max_children: None,
+            root_level: 1
+        }
+    }
+
+    /// Create new empty tree with capacity for a number of nodes.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `capacity` - Number of nodes.
+    /// 
+    /// # Return
+    /// 
+    /// * Tree struct.
+    ///
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            nodes: Vec::with_capacity(capacity),
+            max_children: None,
+            root_level: 1
+        }
+    }
+
+    /// Create new empty tree with a limited number of children per node.
+    /// 
+    /// For example, a binary tree is created with `max_children` = 2.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `max_children` - Maximum number of linked children per node.
+    /// 
+    /// # Return
+    /// 
+    /// * Tree struct.
+    ///
+    pub fn with_max_children(max_children: usize) -> Self {
+        Self {
+            nodes: vec!(),
+            max_children: Some(max_children),
+            root_level: 1
+        }
+    }
+
+    /// Create new empty tree with a custom root level.
+    /// 
+    /// By default the root is at level 1. Children levels are always relative to the root level.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `root_level` - Level of the root node, for example 0.
+    /// 
+    /// # Return
+    /// 
+    /// * Tree struct.
+    ///
+    pub fn new_with_root_level(root_level: usize) -> Self {
+        Self {
+            nodes: vec!(),
+            max_children: None,
+            root_level
+        }
+    }
+
+    /// Create new tree with a root node.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_content` - Content of the root node.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with the tree, or a description of the error if the content can't be parsed.
+    ///
+    pub fn with_root(node_content: &str) -> Result<Self, String> {
+        let mut tree = Self::new();
+        if tree.set_root(node_content).is_none() {
+            return Err(format!("Invalid node content \"{}\"", node_content));
+        }
+        Ok(tree)
+    }
+
+    /// Create a tree from a sequence of build events.
+    /// 
+    /// The first [`BuildEvent::Open`] creates the root node, and every other one links a new node to the currently open node. Events can be generated from [`IterInterface::dfs_events()`].
+    /// 
+    /// # Arguments
+    /// 
+    /// * `events` - Build events.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with the tree, or a description of the error.
+    ///
+    pub fn from_events<I: IntoIterator<Item = BuildEvent>>(events: I) -> Result<Self, String> {
+        let mut tree = Self::new();
+        let mut pila: Vec<usize> = vec!();
+        for event in events {
+            match event {
+                BuildEvent::Open(node_content) => {
+                    let node_index = if let Some(parent_index) = pila.last() {
+                        tree.link_node(&node_content, *parent_index)
+                    }
+                    else if tree.nodes.is_empty() {
+                        tree.set_root(&node_content)
+                    }
+                    else {
+                        return Err(format!("Node \"{}\" opened after closing the root node", node_content));
+                    };
+                    if let Some(node_index) = node_index {
+                        pila.push(node_index);
+                    }
+                    else {
+                        return Err(format!("Invalid node content \"{}\"", node_content));
+                    }
+                },
+                BuildEvent::Close => {
+                    if pila.pop().is_none() {
+                        return Err(String::from("Close without a matching open"));
+                    }
+                }
+            }
+        }
+        if !pila.is_empty() {
+            return Err(format!("{} nodes not closed", pila.len()));
+        }
+        Ok(tree)
+    }
+
+    /// Parse a tree from an indented string, the inverse of [`Tree::gen_string()`].
+    /// 
+    /// Every non-empty line is a node, indented with 2 spaces per level, and its content is parsed with [`NodeContent::new()`]. A backslash after the indentation is removed, it escapes content that is empty or starts with a space or a backslash.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `text` - Serialized tree.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with the tree, or a description of the error.
+    ///
+    pub fn parse_string(text: &str) -> Result<Self, String> {
+        let mut events = vec!();
+        let mut open_nodes = 0;
+        for (line_num, line) in text.lines().enumerate() {
+            let node_content = line.trim_start_matches(' ');
+            if node_content.is_empty() {
+                continue;
+            }
+            let indent = line.len() - node_content.len();
+            if indent % 2 != 0 || indent / 2 > open_nodes {
+                return Err(format!("Invalid indentation at line {}", line_num + 1));
+            }
+            // Close the nodes at the same or deeper levels
+            while open_nodes > indent / 2 {
+                events.push(BuildEvent::Close);
+                open_nodes -= 1;
+            }
+            let node_content = node_content.strip_prefix('\\').unwrap_or(node_content);
+            events.push(BuildEvent::Open(String::from(node_content)));
+            open_nodes += 1;
         }
+        events.extend((0..open_nodes).map(|_| BuildEvent::Close));
+        Self::from_events(events)
+    }
+
+    /// Reserve capacity for additional nodes.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `additional` - Number of additional nodes.
+    /// 
+    /// # Return
+    /// 
+    /// * Nothing.
+    ///
+    pub fn reserve(&mut self, additional: usize) {
+        self.nodes.reserve(additional);
+    }
+
+    /// Shrink the capacity of nodes array as much as possible.
+    /// 
+    /// Unlinked nodes are still part of the array, so this doesn't reclaim the memory they use, only the unused capacity.
+    /// 
+    /// # Return
+    /// 
+    /// * Nothing.
+    ///
+    pub fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+    }
+
+    /// Get capacity of nodes array.
+    /// 
+    /// # Return
+    /// 
+    /// * Number of nodes the tree can hold without reallocating.
+    ///
+    pub fn capacity(&self) -> usize {
+        self.nodes.capacity()
     }
 
     /// Set root node.
@@ -31,8 +274,9 @@ impl<T: NodeContent> Tree<T> {
     /// * An [`Option`] with the root node index (always 0).
     ///
     pub fn set_root(&mut self, node_content: &str) -> Option<usize> {
-        if let Some(node) = Node::<T>::new_root(node_content) {
-            if self.nodes.len() == 0 {
+        if let Some(mut node) = Node::<T>::new_node(node_content, self.root_level) {
+            if self.nodes.is_empty() {
+                node.set_depth(0);
                 // Create root node
                 self.nodes.push(node);
                 return Some(0);
@@ -41,7 +285,11 @@ impl<T: NodeContent> Tree<T> {
         None
     }
 
-    /// Create new node and link it to its parent.
+    /// Create new node and link it to its parent. The parent node must be linked.
+    /// 
+    /// If the tree was created with [`Tree::with_max_children()`], it fails when the parent already has the maximum number of children.
+    /// 
+    /// This process is O(d) complexity, where `d` is the level of `parent_node_index`.
     /// 
     /// # Arguments
     /// 
@@ -53,16 +301,22 @@ impl<T: NodeContent> Tree<T> {
     /// * An [`Option`] with the new node index.
     ///
     pub fn link_node(&mut self, node_content: &str, parent_node_index: usize) -> Option<usize> {
-        if parent_node_index < self.nodes.len() {
+        if let (Some(max_children), Some(num_children)) = (self.max_children, self.num_children(parent_node_index)) {
+            if num_children >= max_children {
+                return None;
+            }
+        }
+        if self.is_linked(parent_node_index) {
             let new_node_level = self.nodes[parent_node_index].get_level() + 1;
             if let Some(mut new_node) = Node::<T>::new_node(node_content, new_node_level) {
                 // Update new node, set parent_position and parents_children_pos
                 new_node.set_parent_position(parent_node_index);
-                let parents_children_pos = self.nodes[parent_node_index].get_num_chuildren();
+                let parents_children_pos = self.nodes[parent_node_index].get_num_children();
                 new_node.set_parents_children_pos(parents_children_pos);
+                new_node.set_depth(self.nodes[parent_node_index].get_depth() + 1);
                 // Add new node to nodes array, to parent's children array and to child_map
                 let new_node_index = self.nodes.len();
-                let node_content = String::from(new_node.get_content_ref().get_val());
+                let node_content = String::from(new_node.get_content_ref().key());
                 self.nodes.push(new_node);
                 self.nodes[parent_node_index].add_child(node_content, new_node_index);
                 return Some(new_node_index);
@@ -71,6 +325,79 @@ impl<T: NodeContent> Tree<T> {
         None
     }
 
+    /// Link a new node, after validating it against its parent.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_content` - Node content.
+    /// * `parent_node_index` - Parent node index.
+    /// * `check` - Function that receives the parent content and the new node content, and returns an error if the new node is not valid.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with the new node index, or a description of the error.
+    ///
+    pub fn link_node_checked<F: Fn(&T, &T) -> Result<(), String>>(&mut self, node_content: &str, parent_node_index: usize, check: F) -> Result<usize, String> {
+        if let Some(parent_node) = self.get_node(parent_node_index) {
+            if let Some(new_content) = T::new(node_content) {
+                check(parent_node.get_content_ref(), &new_content)?;
+            }
+            else {
+                return Err(format!("Invalid node content \"{}\"", node_content));
+            }
+        }
+        else {
+            return Err(format!("Parent node {} is not linked", parent_node_index));
+        }
+        self.link_node(node_content, parent_node_index).ok_or_else(|| format!("Node {} can't have more children", parent_node_index))
+    }
+
+    /// Link several new nodes to the same parent, in order.
+    /// 
+    /// All nodes are validated before linking anything, so if any of them fails, no node is linked.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `contents` - Nodes content.
+    /// * `parent_node_index` - Parent node index.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with the array of new node indexes, or a description of the error.
+    ///
+    pub fn link_nodes(&mut self, contents: &[&str], parent_node_index: usize) -> Result<Vec<usize>, String> {
+        let parent_node = self.get_node(parent_node_index).ok_or_else(|| format!("Parent node {} is not linked", parent_node_index))?;
+        if let (Some(max_children), Some(num_children)) = (self.max_children, self.num_children(parent_node_index)) {
+            if num_children + contents.len() > max_children {
+                return Err(format!("Node {} can't have more children", parent_node_index));
+            }
+        }
+        let mut new_vals: Vec<String> = vec!();
+        for node_content in contents.iter() {
+            if let Some(new_content) = T::new(node_content) {
+                let new_val = String::from(new_content.key());
+                if parent_node.get_child(&new_val).is_some() || new_vals.contains(&new_val) {
+                    return Err(format!("Node {} already has a child named \"{}\"", parent_node_index, new_val));
+                }
+                new_vals.push(new_val);
+            }
+            else {
+                return Err(format!("Invalid node content \"{}\"", node_content));
+            }
+        }
+        Ok(contents.iter().filter_map(|node_content| self.link_node(node_content, parent_node_index)).collect())
+    }
+
+    /// Get reference to root node content.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the root node content reference, None if the tree is empty.
+    ///
+    pub fn root_content(&self) -> Option<&T> {
+        self.get_node_content(0)
+    }
+
     /// Get reference to node content.
     /// 
     /// # Arguments
@@ -88,36 +415,41 @@ impl<T: NodeContent> Tree<T> {
         None
     }
     
-    /// Overwrite node content. It must exist.
+    /// Get reference to node. It must be linked.
     /// 
     /// # Arguments
     /// 
-    /// * `node_content` - Node content.
     /// * `node_index` - Node index.
     /// 
     /// # Return
     /// 
-    /// * An [`Option`] with the node index.
-    ///
-    pub fn update_node(&mut self, node_content: &str, node_index: usize) -> Option<usize> {
-        if self.nodes.len() > node_index {
-            if let Some(new_node) = Node::<T>::new_node(node_content, self.nodes[node_index].get_level()) {
-                // Update parent's child_map
-                if let Some(parent_position) = self.nodes[node_index].get_parent_position() {
-                    let old_node_content = String::from(self.nodes[node_index].get_content_ref().get_val());
-                    self.nodes[parent_position].update_child(&old_node_content, node_content);
-                }
-                let current_node = self.nodes.get_mut(node_index).unwrap();
-                current_node.set_content(new_node.get_content());
-                return Some(node_index);
-            }
+    /// * An [`Option`] with the node reference.
+    /// 
+    pub fn get_node(&self, node_index: usize) -> Option<&Node<T>> {
+        if self.is_linked(node_index) {
+            return Some(&self.nodes[node_index]);
         }
         None
     }
 
-    /// Unlink node. It doesn't remove node from the tree, it just disconnects it from parent.
+    /// Get parent node index.
     /// 
-    /// This process is O(l) complexity, where `l` is the number of nodes of the same level of `node_index`.
+    /// # Arguments
+    /// 
+    /// * `node_index` - Node index.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the parent node index.
+    ///
+    pub fn parent(&self, node_index: usize) -> Option<usize> {
+        if let Some(node) = self.nodes.get(node_index) {
+            return node.get_parent_position();
+        }
+        None
+    }
+
+    /// Get the indexes of the children of a node, excluding the unlinked ones.
     /// 
     /// # Arguments
     /// 
@@ -125,108 +457,1701 @@ impl<T: NodeContent> Tree<T> {
     /// 
     /// # Return
     /// 
-    /// * An [`Option`] with the node index.
+    /// * An [`Option`] with the array of children indexes.
     ///
-    pub fn unlink_node(&mut self, node_index: usize) -> Option<usize> {
-        if self.nodes.len() > node_index {
-            if let Some(parent) = self.nodes[node_index].get_parent_position() {
-                if let Some(parents_children_pos) = self.nodes[node_index].get_parents_children_pos() {
-                    if self.nodes[parent].get_num_chuildren() > parents_children_pos {
-                        let node_content = String::from(self.nodes[node_index].get_content_ref().get_val());
-                        self.nodes[parent].remove_child(&node_content, parents_children_pos);
-                        return Some(node_index);
-                    }
-                }
-            }
+    pub fn children_indexes(&self, node_index: usize) -> Option<Vec<usize>> {
+        if let Some(node) = self.nodes.get(node_index) {
+            return Some(node.get_children_ref().iter().filter(|child| **child != usize::MAX).copied().collect());
         }
         None
     }
 
-    /// Find node in the try by content.
+    /// Get the nth linked child of a node, skipping the unlinked ones.
     /// 
-    /// The complexity of this operation is O(p), where `p` is the number of elements in the path.
+    /// # Arguments
+    /// 
+    /// * `parent_index` - Parent node index.
+    /// * `n` - Position of the child, starting at 0.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the child index.
+    ///
+    pub fn nth_child(&self, parent_index: usize, n: usize) -> Option<usize> {
+        self.nodes.get(parent_index)?.get_children_ref().iter().filter(|child| **child != usize::MAX).nth(n).copied()
+    }
+
+    /// Get the next linked sibling of a node.
     /// 
     /// # Arguments
     /// 
-    /// * `path` - Path of nodes, starting from root.
+    /// * `node_index` - Node index.
     /// 
     /// # Return
     /// 
-    /// * An [`Option`] with the node index.
+    /// * An [`Option`] with the sibling index.
     ///
-    pub fn find_node(&self, path: &[&str]) -> Option<usize> {
-        let mut last_node_index = None;
-        // Check root node
-        if self.nodes.len() > 0 && path.len() > 0 {
-            if self.nodes[0].get_content_ref().get_val() == path[0] {
-                last_node_index = Some(0);
-            }
-            else {
-                return None;
-            }
-        }
-        // Check following nodes
-        let mut node_index = 0;
-        for path_element in path[1..].iter() {
-            if self.nodes.len() > node_index {
-                if let Some(path_element_index) = self.nodes[node_index].get_child(path_element) {
-                    last_node_index = Some(path_element_index);
-                    node_index = path_element_index;
-                }
-                else {
-                    return None;
-                }
-            }
-            else {
-                return None;
-            }
-        }
-        last_node_index
+    pub fn next_sibling(&self, node_index: usize) -> Option<usize> {
+        let (siblings, position) = self.sibling_slots(node_index)?;
+        siblings[position + 1..].iter().find(|sibling| **sibling != usize::MAX).copied()
     }
 
-    //TODO: traverse starting by a certain node, not root. Pass node index as argument.
-    /// Get iterators interface.
+    /// Get the previous linked sibling of a node.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_index` - Node index.
     /// 
     /// # Return
     /// 
-    /// * Iterators interface.
+    /// * An [`Option`] with the sibling index.
     ///
-    pub fn iterators(&self) -> IterInterface<T> {
-        IterInterface::new(self)
+    pub fn prev_sibling(&self, node_index: usize) -> Option<usize> {
+        let (siblings, position) = self.sibling_slots(node_index)?;
+        siblings[..position].iter().rev().find(|sibling| **sibling != usize::MAX).copied()
     }
 
-    /// Get reference to nodes array.
+    /// Get pairs of adjacent linked children of a node.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `parent_index` - Parent node index.
     /// 
     /// # Return
     /// 
-    /// * Array reference.
+    /// * Iterator, provides a tuple with the indexes of two consecutive children. Empty if the node doesn't exist or has less than two children.
     ///
-    pub fn get_nodes_ref(&self) -> &[Node<T>] {
-        &self.nodes
+    pub fn child_pairs(&self, parent_index: usize) -> impl Iterator<Item = (usize, usize)> {
+        let children = self.children_indexes(parent_index).unwrap_or_default();
+        let next_children = children.clone();
+        children.into_iter().zip(next_children.into_iter().skip(1))
     }
 
-    /// Get size of nodes array.
+    /// Get the content of the children of a node.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_index` - Node index.
     /// 
     /// # Return
     /// 
-    /// * Size.
+    /// * Iterator, provides the content reference of each linked child. Empty if the node doesn't exist or is unlinked.
     ///
-    pub fn get_nodes_len(&self) -> usize {
-        self.nodes.len()
+    pub fn children_content(&self, node_index: usize) -> impl Iterator<Item = &T> + '_ {
+        ChildrenIter::new(self, node_index).map(|(node, _)| node.get_content_ref())
     }
 
-    // TODO
-    /*
-    /// Obtain a copy of the current tree without unlinked nodes and updating node indexes.
+    /// Get the children of a node sorted, without changing their order in the tree.
     /// 
-    /// Node indexes of the old tree are no longer valid in the new tree returned by this function.
+    /// # Arguments
+    /// 
+    /// * `parent_index` - Parent node index.
+    /// * `cmp` - Function that compares the content of two children.
     /// 
     /// # Return
     /// 
-    /// * Regenerated tree.
+    /// * Iterator, provides a tuple with node<[`Node`]> and node_index<[`usize`]> of each linked child, in the order defined by `cmp`.
     ///
-    pub fn regenerate(&self) -> Self {
-        Tree::new()
+    pub fn children_sorted_by<'a, F: Fn(&T, &T) -> Ordering>(&'a self, parent_index: usize, cmp: F) -> impl Iterator<Item = (&'a Node<T>, usize)> {
+        let mut children: Vec<(&'a Node<T>, usize)> = ChildrenIter::new(self, parent_index).collect();
+        children.sort_by(|(node_a, _), (node_b, _)| cmp(node_a.get_content_ref(), node_b.get_content_ref()));
+        children.into_iter()
+    }
+
+    /// Run a function for every linked child of a node, with mutable access.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `parent_index` - Parent node index.
+    /// * `f` - Function that receives the mutable child node.
+    /// 
+    /// # Return
+    /// 
+    /// * Nothing.
+    ///
+    pub fn for_each_child_mut<F: FnMut(&mut Node<T>)>(&mut self, parent_index: usize, mut f: F) {
+        if let Some(children) = self.children_indexes(parent_index) {
+            for child_index in children {
+                f(&mut self.nodes[child_index]);
+            }
+        }
+    }
+
+    /// Run a function for every ancestor of a node, with mutable access, from the parent up to the root node.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `start_index` - Node index, the function is not run for this node.
+    /// * `f` - Function that receives the mutable ancestor node.
+    /// 
+    /// # Return
+    /// 
+    /// * Nothing.
+    ///
+    pub fn for_each_ancestor_mut<F: FnMut(&mut Node<T>)>(&mut self, start_index: usize, mut f: F) {
+        let mut current_index = start_index;
+        while let Some(parent) = self.parent(current_index) {
+            f(&mut self.nodes[parent]);
+            current_index = parent;
+        }
+    }
+
+    /// Get number of children of a node, excluding the unlinked ones.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_index` - Node index.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the number of children.
+    ///
+    pub fn num_children(&self, node_index: usize) -> Option<usize> {
+        if let Some(node) = self.nodes.get(node_index) {
+            return Some(node.get_children_ref().iter().filter(|child| **child != usize::MAX).count());
+        }
+        None
+    }
+
+    /// Overwrite node content. It must exist.
+    /// 
+    /// The update is transactional: if the content can't be parsed, or a sibling already has the new key, nothing is changed.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_content` - Node content.
+    /// * `node_index` - Node index.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the node index.
+    ///
+    pub fn update_node(&mut self, node_content: &str, node_index: usize) -> Option<usize> {
+        if self.nodes.len() > node_index {
+            if let Some(new_node) = Node::<T>::new_node(node_content, self.nodes[node_index].get_level()) {
+                let old_val = String::from(self.nodes[node_index].get_content_ref().key());
+                let new_val = String::from(new_node.get_content_ref().key());
+                // Only the parent that still has the node as child, an unlinked node keeps a stale parent position
+                let parent_position = self.sibling_slots(node_index).and(self.nodes[node_index].get_parent_position());
+                if let Some(parent_position) = parent_position {
+                    // Check parent's child_map can be updated before changing anything
+                    let parent = self.nodes.get_mut(parent_position)?;
+                    if old_val != new_val && parent.get_child(&new_val).is_some() {
+                        return None;
+                    }
+                    // Update parent's child_map
+                    parent.update_child(&old_val, &new_val);
+                }
+                self.nodes[node_index].set_content(new_node.get_content());
+                return Some(node_index);
+            }
+        }
+        None
+    }
+
+    /// Unlink node. It doesn't remove node from the tree, it just disconnects it from parent.
+    /// 
+    /// This process is O(l) complexity, where `l` is the number of nodes of the same level of `node_index`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_index` - Node index.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the node index.
+    ///
+    pub fn unlink_node(&mut self, node_index: usize) -> Option<usize> {
+        if self.nodes.len() > node_index {
+            if let Some(parent) = self.nodes[node_index].get_parent_position() {
+                if let Some(parents_children_pos) = self.nodes[node_index].get_parents_children_pos() {
+                    if self.nodes[parent].get_num_children() > parents_children_pos {
+                        let node_content = String::from(self.nodes[node_index].get_content_ref().key());
+                        self.nodes[parent].remove_child(&node_content, parents_children_pos);
+                        return Some(node_index);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Unlink a node and get its subtree as a new tree.
+    /// 
+    /// Every new node content is parsed from the generated content of the current one, see [`NodeContent::gen_content()`]. The nodes of the subtree are kept in the original tree as unlinked nodes.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_index` - Node index, it will be the root of the new tree.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with the detached tree, or a description of the error. On error the tree is not modified.
+    ///
+    pub fn detach_subtree(&mut self, node_index: usize) -> Result<Tree<T>, String> {
+        if node_index == 0 {
+            return Err(String::from("Root node can't be detached"));
+        }
+        if !self.is_linked(node_index) {
+            return Err(format!("Node {} is not linked", node_index));
+        }
+        let mut tree = Tree {
+            nodes: Vec::with_capacity(self.subtree_size(node_index).unwrap_or_default()),
+            max_children: self.max_children,
+            root_level: self.root_level
+        };
+        let mut pila = vec!((node_index, None));
+        while let Some((current_index, new_parent)) = pila.pop() {
+            let node_content = self.nodes[current_index].get_content_ref().gen_content();
+            let new_index = match new_parent {
+                Some(new_parent) => tree.link_node(&node_content, new_parent),
+                None => tree.set_root(&node_content)
+            };
+            if let Some(new_index) = new_index {
+                pila.extend(self.children_indexes(current_index).unwrap_or_default().into_iter().rev().map(|child| (child, Some(new_index))));
+            }
+            else {
+                return Err(format!("Invalid node content \"{}\"", node_content));
+            }
+        }
+        self.unlink_node(node_index);
+        Ok(tree)
+    }
+
+    /// Remove the holes left by unlinked children of a node, updating the position of the remaining children.
+    /// 
+    /// Unlike a full regeneration, only the node and its linked children are modified.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `parent_index` - Parent node index.
+    /// 
+    /// # Return
+    /// 
+    /// * Nothing.
+    ///
+    pub fn compact_children(&mut self, parent_index: usize) {
+        if let Some(node) = self.nodes.get_mut(parent_index) {
+            node.compact_children();
+            let children = node.get_children_ref().to_vec();
+            for (parents_children_pos, child_index) in children.into_iter().enumerate() {
+                self.nodes[child_index].set_parents_children_pos(parents_children_pos);
+            }
+        }
+    }
+
+    /// Unlink a node and free the slots of its subtree, if possible.
+    /// 
+    /// The slots are only freed if the subtree occupies the end of the nodes array, for example the last subtree added. Otherwise they are kept as dead slots, like [`Tree::unlink_node()`] does, and [`Tree::prune_dead()`] can be used to free their content.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_index` - Node index.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with nothing, or a description of the error.
+    ///
+    pub fn remove_and_compact(&mut self, node_index: usize) -> Result<(), String> {
+        if node_index == 0 || !self.is_linked(node_index) {
+            return Err(format!("Node {} is not a linked child", node_index));
+        }
+        let mut subtree = self.subtree_indexes(node_index);
+        self.unlink_node(node_index);
+        subtree.sort_unstable();
+        // Subtree slots are contiguous at the end of the array
+        if subtree.iter().enumerate().all(|(i, index)| *index == self.nodes.len() - subtree.len() + i) {
+            self.nodes.truncate(self.nodes.len() - subtree.len());
+        }
+        Ok(())
+    }
+
+    /// Move a node, along with its subtree, to a new parent. The node can be linked or unlinked, and the new parent must be linked.
+    /// 
+    /// The node is linked as last child of the new parent, and the levels of its subtree are recalculated.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_index` - Node index.
+    /// * `new_parent_index` - New parent node index.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with nothing, or a description of the error. On error the tree is not modified.
+    ///
+    pub fn relink_node(&mut self, node_index: usize, new_parent_index: usize) -> Result<(), String> {
+        if node_index == 0 || node_index >= self.nodes.len() {
+            return Err(format!("Node {} can't be relinked", node_index));
+        }
+        if !self.is_linked(new_parent_index) {
+            return Err(format!("Parent node {} is not linked", new_parent_index));
+        }
+        self.assert_no_cycle(node_index, new_parent_index)?;
+        let node_val = String::from(self.nodes[node_index].get_content_ref().key());
+        let is_current_parent = self.is_linked(node_index) && self.parent(node_index) == Some(new_parent_index);
+        if !is_current_parent {
+            if self.nodes[new_parent_index].get_child(&node_val).is_some() {
+                return Err(format!("Node {} already has a child named \"{}\"", new_parent_index, node_val));
+            }
+            if let (Some(max_children), Some(num_children)) = (self.max_children, self.num_children(new_parent_index)) {
+                if num_children >= max_children {
+                    return Err(format!("Node {} can't have more children", new_parent_index));
+                }
+            }
+        }
+        // Remove from the current parent, even if it is unlinked
+        if self.sibling_slots(node_index).is_some() {
+            self.unlink_node(node_index);
+        }
+        // Link to the new parent
+        let parents_children_pos = self.nodes[new_parent_index].get_num_children();
+        self.nodes[new_parent_index].add_child(node_val, node_index);
+        self.nodes[node_index].set_parent_position(new_parent_index);
+        self.nodes[node_index].set_parents_children_pos(parents_children_pos);
+        // Recalculate levels
+        let mut pila = vec!((node_index, self.nodes[new_parent_index].get_level() + 1));
+        while let Some((current_index, level)) = pila.pop() {
+            if let Some(node) = self.nodes.get_mut(current_index) {
+                node.set_level(level);
+                node.set_depth(level - self.root_level);
+                pila.extend(node.get_children_ref().iter().map(|child| (*child, level + 1)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Move all the children of a node, along with their subtrees, to another node.
+    /// 
+    /// Children are linked as last children of the new parent, keeping their order, and the levels of their subtrees are recalculated.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `from_index` - Node index of the current parent.
+    /// * `to_index` - Node index of the new parent.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with nothing, or a description of the error. On error the tree is not modified.
+    ///
+    pub fn move_children(&mut self, from_index: usize, to_index: usize) -> Result<(), String> {
+        if !self.is_linked(from_index) {
+            return Err(format!("Node {} is not linked", from_index));
+        }
+        if !self.is_linked(to_index) {
+            return Err(format!("Parent node {} is not linked", to_index));
+        }
+        if from_index == to_index {
+            return Ok(());
+        }
+        self.assert_no_cycle(from_index, to_index)?;
+        let children = self.children_indexes(from_index).unwrap_or_default();
+        // Check all children before moving anything, including key clashes between the moved children
+        let mut moved_keys = Map::new();
+        for child_index in children.iter() {
+            let child_val = self.nodes[*child_index].get_content_ref().key();
+            if self.nodes[to_index].get_child(child_val).is_some() || moved_keys.insert(child_val, *child_index).is_some() {
+                return Err(format!("Node {} already has a child named \"{}\"", to_index, child_val));
+            }
+        }
+        if let (Some(max_children), Some(num_children)) = (self.max_children, self.num_children(to_index)) {
+            if num_children + children.len() > max_children {
+                return Err(format!("Node {} can't have more children", to_index));
+            }
+        }
+        for child_index in children {
+            self.relink_node(child_index, to_index)?;
+        }
+        Ok(())
+    }
+
+    /// Unlink all nodes deeper than a certain depth.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `max_depth` - Maximum depth, the distance from the root node (depth 0).
+    /// 
+    /// # Return
+    /// 
+    /// * Nothing.
+    ///
+    pub fn prune_to_depth(&mut self, max_depth: usize) {
+        // Unlink the children of the deepest nodes allowed, the nodes below them become unreachable
+        if let Some(deepest_nodes) = self.levels().get(max_depth) {
+            for node_index in deepest_nodes.iter() {
+                for child_index in self.children_indexes(*node_index).unwrap_or_default() {
+                    self.unlink_node(child_index);
+                }
+            }
+        }
+    }
+
+    /// Check if a node is linked, that is, reachable from the root node.
+    /// 
+    /// This process is O(d) complexity, where `d` is the level of `node_index`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_index` - Node index.
+    /// 
+    /// # Return
+    /// 
+    /// * True if the node exists and is linked, false otherwise.
+    ///
+    pub fn is_linked(&self, node_index: usize) -> bool {
+        let mut current_index = node_index;
+        while let Some(node) = self.nodes.get(current_index) {
+            if let (Some(parent), Some(parents_children_pos)) = (node.get_parent_position(), node.get_parents_children_pos()) {
+                // Parent must still have the current node in its children array
+                if let Some(parent_node) = self.nodes.get(parent) {
+                    if parent_node.get_children_ref().get(parents_children_pos) == Some(&current_index) {
+                        current_index = parent;
+                        continue;
+                    }
+                }
+                return false;
+            }
+            // Node without parent, only the root node is linked
+            return current_index == 0;
+        }
+        false
+    }
+
+    /// Find node in the try by content.
+    /// 
+    /// The complexity of this operation is O(p), where `p` is the number of elements in the path.
+    /// 
+    /// When a path element is not found by exact key, [`NodeContent::matches()`] is checked against every child, making the complexity O(p·b) in the worst case, where `b` is the number of children per node.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `path` - Path of nodes, starting from root.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the node index. None if the tree or the path are empty.
+    ///
+    pub fn find_node(&self, path: &[&str]) -> Option<usize> {
+        // Check root node, the tree and the path can't be empty
+        if self.nodes.is_empty() || path.is_empty() || !(self.nodes[0].get_content_ref().key() == path[0] || self.nodes[0].get_content_ref().matches(path[0])) {
+            return None;
+        }
+        let mut last_node_index = Some(0);
+        // Check following nodes
+        let mut node_index = 0;
+        for path_element in path[1..].iter() {
+            if self.nodes.len() > node_index {
+                if let Some(path_element_index) = self.find_child(node_index, path_element) {
+                    last_node_index = Some(path_element_index);
+                    node_index = path_element_index;
+                }
+                else {
+                    return None;
+                }
+            }
+            else {
+                return None;
+            }
+        }
+        last_node_index
+    }
+
+    /// Walk a path of nodes and get the index of every node found.
+    /// 
+    /// The complexity of this operation is O(p), where `p` is the number of elements in the path.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `initial_node` - Node index where the path starts.
+    /// * `path` - Path of nodes, children of `initial_node`.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the array of node indexes, starting with `initial_node`.
+    ///
+    pub fn walk_path(&self, initial_node: usize, path: &[&str]) -> Option<Vec<usize>> {
+        if initial_node < self.nodes.len() {
+            let mut node_indexes = vec!(initial_node);
+            let mut node_index = initial_node;
+            for path_element in path.iter() {
+                node_index = self.find_child(node_index, path_element)?;
+                node_indexes.push(node_index);
+            }
+            return Some(node_indexes);
+        }
+        None
+    }
+
+    /// Check if a path of nodes exists.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `initial_node` - Node index where the path starts.
+    /// * `path` - Path of nodes, children of `initial_node`.
+    /// 
+    /// # Return
+    /// 
+    /// * True if the path can be walked.
+    ///
+    pub fn contains_path(&self, initial_node: usize, path: &[&str]) -> bool {
+        self.walk_path(initial_node, path).is_some()
+    }
+
+    /// Get the level of the node found walking a path of nodes.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `initial_node` - Node index where the path starts.
+    /// * `path` - Path of nodes, children of `initial_node`.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the node level, or None if the path can't be walked.
+    ///
+    pub fn level_of_path(&self, initial_node: usize, path: &[&str]) -> Option<usize> {
+        let node_index = *self.walk_path(initial_node, path)?.last()?;
+        Some(self.nodes[node_index].get_level())
+    }
+
+    /// Find a node by path, comparing path elements with node keys case-insensitively.
+    /// 
+    /// Children are scanned linearly at every step, making the complexity O(p·b), where `p` is the number of elements in the path and `b` is the number of children per node.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `initial_node` - Node index where the path starts.
+    /// * `path` - Path of nodes, children of `initial_node`.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the node index.
+    ///
+    pub fn find_path_ci(&self, initial_node: usize, path: &[&str]) -> Option<usize> {
+        if initial_node >= self.nodes.len() {
+            return None;
+        }
+        let mut node_index = initial_node;
+        for path_element in path.iter() {
+            node_index = self.nodes[node_index].get_children_ref().iter().copied().find(|child| {
+                match self.nodes.get(*child) {
+                    Some(child_node) => {
+                        let key = child_node.get_content_ref().key();
+                        key.chars().flat_map(char::to_lowercase).eq(path_element.chars().flat_map(char::to_lowercase))
+                    },
+                    None => false
+                }
+            })?;
+        }
+        Some(node_index)
+    }
+
+    /// Get the path of a node, the inverse of [`Tree::find_node()`].
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_index` - Node index.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the path of node keys, starting from root.
+    ///
+    pub fn node_path(&self, node_index: usize) -> Option<Vec<String>> {
+        let mut path = vec!();
+        let mut current_node = self.nodes.get(node_index)?;
+        loop {
+            path.push(String::from(current_node.get_content_ref().key()));
+            if let Some(parent) = current_node.get_parent_position() {
+                current_node = self.nodes.get(parent)?;
+            }
+            else {
+                break;
+            }
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Get the indexes of all ancestors of a node, like [`Tree::node_path()`] but with indexes.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_index` - Node index.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the array of node indexes, starting from root and ending with the node itself.
+    ///
+    pub fn ancestors_of(&self, node_index: usize) -> Option<Vec<usize>> {
+        let mut chain = vec!();
+        let mut current_index = node_index;
+        loop {
+            let node = self.nodes.get(current_index)?;
+            chain.push(current_index);
+            if let Some(parent) = node.get_parent_position() {
+                current_index = parent;
+            }
+            else {
+                break;
+            }
+        }
+        chain.reverse();
+        Some(chain)
+    }
+
+    /// Check if a node is an ancestor of another node.
+    /// 
+    /// This process is O(d) complexity, where `d` is the level of `node_index`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `ancestor_index` - Ancestor node index.
+    /// * `node_index` - Node index.
+    /// 
+    /// # Return
+    /// 
+    /// * True if `ancestor_index` is in the parent chain of `node_index`.
+    ///
+    pub fn is_ancestor(&self, ancestor_index: usize, node_index: usize) -> bool {
+        let mut current_index = node_index;
+        while let Some(parent) = self.parent(current_index) {
+            if parent == ancestor_index {
+                return true;
+            }
+            current_index = parent;
+        }
+        false
+    }
+
+    /// Check if a node is a descendant of another node.
+    /// 
+    /// This process is O(d) complexity, where `d` is the level of `node_index`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_index` - Node index.
+    /// * `ancestor_index` - Ancestor node index.
+    /// 
+    /// # Return
+    /// 
+    /// * True if `node_index` is in the subtree of `ancestor_index`.
+    ///
+    pub fn is_descendant(&self, node_index: usize, ancestor_index: usize) -> bool {
+        self.is_ancestor(ancestor_index, node_index)
+    }
+
+    /// Get number of nodes in the subtree of a node, including itself.
+    /// 
+    /// This process is O(n) complexity, where `n` is the number of nodes in the subtree.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_index` - Node index.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the subtree size, or [`None`] if the node doesn't exist or is not linked.
+    ///
+    pub fn subtree_size(&self, node_index: usize) -> Option<usize> {
+        if self.is_linked(node_index) {
+            return Some(self.subtree_indexes(node_index).len());
+        }
+        None
+    }
+
+    /// Get the subtree size of all nodes, in a single pass.
+    /// 
+    /// This process is O(n) complexity, where `n` is the number of nodes.
+    /// 
+    /// # Return
+    /// 
+    /// * Array with the subtree size of each node, indexed by node index. Dead nodes have size 0.
+    ///
+    pub fn descendants_count_map(&self) -> Vec<usize> {
+        let mut sizes = vec!(0; self.nodes.len());
+        for (_, node_index, size) in self.iterators().post_dfs_with_size() {
+            sizes[node_index] = size;
+        }
+        sizes
+    }
+
+    /// Find the first node that satisfies a condition, in a certain traversal order.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `order` - Traversal order, see [`IterInterface::traverse()`].
+    /// * `pred` - Function that receives the node content and returns true if it is the node to find.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the node index.
+    ///
+    pub fn find_first<F: Fn(&T) -> bool>(&self, order: TraversalOrder, pred: F) -> Option<usize> {
+        self.iterators().traverse(order).find(|(node, _)| pred(node.get_content_ref())).map(|(_, node_index)| node_index)
+    }
+
+    /// Count the linked nodes that satisfy a condition.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `pred` - Function that receives the node content and returns true if it must be counted.
+    /// 
+    /// # Return
+    /// 
+    /// * Number of nodes.
+    ///
+    pub fn count_matching<F: Fn(&T) -> bool>(&self, pred: F) -> usize {
+        self.subtree_indexes(0).into_iter().filter(|node_index| pred(self.nodes[*node_index].get_content_ref())).count()
+    }
+
+    /// Get number of leaves, linked nodes without linked children.
+    /// 
+    /// This process is O(n) complexity, where `n` is the number of nodes.
+    /// 
+    /// # Return
+    /// 
+    /// * Number of leaves.
+    ///
+    pub fn leaves_count(&self) -> usize {
+        self.subtree_indexes(0).into_iter().filter(|node_index| self.num_children(*node_index) == Some(0)).count()
+    }
+
+    /// Get number of internal nodes, linked nodes with linked children.
+    /// 
+    /// This process is O(n) complexity, where `n` is the number of nodes.
+    /// 
+    /// # Return
+    /// 
+    /// * Number of internal nodes.
+    ///
+    pub fn internal_count(&self) -> usize {
+        self.subtree_indexes(0).into_iter().filter(|node_index| self.num_children(*node_index) != Some(0)).count()
+    }
+
+    /// Get maximum degree, the largest number of linked children of a linked node.
+    /// 
+    /// This process is O(n) complexity, where `n` is the number of nodes.
+    /// 
+    /// # Return
+    /// 
+    /// * Maximum degree.
+    ///
+    pub fn max_degree(&self) -> usize {
+        self.subtree_indexes(0).into_iter().map(|node_index| self.nodes[node_index].degree()).max().unwrap_or(0)
+    }
+
+    /// Get maximum number of linked nodes at a single level.
+    /// 
+    /// This process is O(n) complexity, where `n` is the number of nodes.
+    /// 
+    /// # Return
+    /// 
+    /// * Maximum breadth.
+    ///
+    pub fn max_breadth(&self) -> usize {
+        self.iterators().by_level().map(|(_, nodes)| nodes.len()).max().unwrap_or(0)
+    }
+
+    /// Get number of linked nodes at each level.
+    /// 
+    /// This process is O(n) complexity, where `n` is the number of nodes.
+    /// 
+    /// # Return
+    /// 
+    /// * Array with the number of nodes, where position 0 corresponds to the root level (level 1 by default), position 1 to the next level, and so on.
+    ///
+    pub fn count_by_level(&self) -> Vec<usize> {
+        self.iterators().by_level().map(|(_, nodes)| nodes.len()).collect()
+    }
+
+    /// Get total path length, the sum of the depths of all linked nodes.
+    /// 
+    /// This process is O(n) complexity, where `n` is the number of nodes.
+    /// 
+    /// # Return
+    /// 
+    /// * Total path length, root node has depth 0.
+    ///
+    pub fn total_path_length(&self) -> usize {
+        self.count_by_level().into_iter().enumerate().map(|(depth, num_nodes)| depth * num_nodes).sum()
+    }
+
+    /// Check if the tree is balanced, that is, for every node the heights of its children subtrees differ by at most 1.
+    /// 
+    /// Heights are computed bottom-up in a single pass. A node with less children than the maximum (2 if the tree has no maximum, see [`Tree::with_max_children()`]) also has a missing subtree of height 0, so a long chain is not balanced.
+    /// 
+    /// This process is O(n) complexity, where `n` is the number of nodes.
+    /// 
+    /// # Return
+    /// 
+    /// * True if the tree is balanced. An empty tree is balanced.
+    ///
+    pub fn is_balanced(&self) -> bool {
+        let max_children = self.max_children.unwrap_or(2);
+        let mut heights = vec!(0; self.nodes.len());
+        // In reverse Pre-Order DFS children are visited before their parents
+        for node_index in self.subtree_indexes(0).into_iter().rev() {
+            let child_heights: Vec<usize> = self.nodes[node_index].get_children_ref().iter().filter_map(|child| heights.get(*child).copied()).collect();
+            let mut min_height = child_heights.iter().copied().min().unwrap_or_default();
+            let max_height = child_heights.iter().copied().max().unwrap_or_default();
+            if child_heights.len() < max_children {
+                min_height = 0;
+            }
+            if max_height - min_height > 1 {
+                return false;
+            }
+            heights[node_index] = max_height + 1;
+        }
+        true
+    }
+
+    /// Get the indexes of all linked nodes, grouped by level.
+    /// 
+    /// # Return
+    /// 
+    /// * Array of levels, starting with the root level, each one with the node indexes from left to right.
+    ///
+    pub fn levels(&self) -> Vec<Vec<usize>> {
+        self.iterators().by_level().map(|(_, nodes)| nodes.into_iter().map(|(_, node_index)| node_index).collect()).collect()
+    }
+
+    /// Get a node at the maximum level.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the index of the first deepest node found in BFS, None if the tree is empty.
+    ///
+    pub fn deepest_node(&self) -> Option<usize> {
+        let (_, deepest_nodes) = self.iterators().by_level().last()?;
+        deepest_nodes.first().map(|(_, node_index)| *node_index)
+    }
+
+    /// Get all linked nodes at a level.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `level` - Node level, the root is at level 1 by default.
+    /// 
+    /// # Return
+    /// 
+    /// * Array of node indexes, from left to right. Empty if there is no node at that level.
+    ///
+    pub fn level_nodes(&self, level: usize) -> Vec<usize> {
+        // Levels are generated in BFS order, stop once we reach the requested one
+        if let Some((_, nodes)) = self.iterators().by_level().take_while(|(node_level, _)| *node_level <= level).find(|(node_level, _)| *node_level == level) {
+            return nodes.into_iter().map(|(_, node_index)| node_index).collect();
+        }
+        vec!()
+    }
+
+    /// Get the common path of two nodes.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_a` - First node index.
+    /// * `node_b` - Second node index.
+    /// 
+    /// # Return
+    /// 
+    /// * Array of node indexes, from root to the lowest common ancestor of both nodes. Empty if any node doesn't exist.
+    ///
+    pub fn common_prefix(&self, node_a: usize, node_b: usize) -> Vec<usize> {
+        if let (Some(chain_a), Some(chain_b)) = (self.ancestors_of(node_a), self.ancestors_of(node_b)) {
+            return chain_a.iter().zip(chain_b.iter()).take_while(|(a, b)| a == b).map(|(a, _)| *a).collect();
+        }
+        vec!()
+    }
+
+    /// Check if two trees have the same shape, the same number and arrangement of linked children at every node, ignoring the content.
+    /// 
+    /// This process is O(n) complexity, where `n` is the number of nodes.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other tree, can have a different node content type.
+    /// 
+    /// # Return
+    /// 
+    /// * True if both trees have the same shape. Two empty trees have the same shape.
+    ///
+    pub fn equals_shape<U: NodeContent>(&self, other: &Tree<U>) -> bool {
+        if self.nodes.is_empty() || other.nodes.is_empty() {
+            return self.nodes.is_empty() == other.nodes.is_empty();
+        }
+        let mut pila = vec!((0, 0));
+        while let Some((node_index, other_index)) = pila.pop() {
+            let children = self.children_indexes(node_index).unwrap_or_default();
+            let other_children = other.children_indexes(other_index).unwrap_or_default();
+            if children.len() != other_children.len() {
+                return false;
+            }
+            pila.extend(children.into_iter().zip(other_children));
+        }
+        true
+    }
+
+    /// Get the edit operations that transform this tree into another one.
+    /// 
+    /// Nodes are matched by path, so a node whose key changed appears as removed and then added. An added subtree generates one [`TreeEdit::Added`] per node, in Pre-Order DFS, while a removed subtree generates a single [`TreeEdit::Removed`].
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - Tree to compare with.
+    /// 
+    /// # Return
+    /// 
+    /// * Array of edit operations. Empty if both trees are equal.
+    ///
+    pub fn diff(&self, other: &Tree<T>) -> Vec<TreeEdit> {
+        let mut edits = vec!();
+        match (self.nodes.first(), other.nodes.first()) {
+            (Some(root), Some(other_root)) if root.get_content_ref().key() == other_root.get_content_ref().key() => {
+                let mut pila = vec!((0, 0, vec!(String::from(root.get_content_ref().key()))));
+                while let Some((node_index, other_index, path)) = pila.pop() {
+                    let node = &self.nodes[node_index];
+                    let other_node = &other.nodes[other_index];
+                    let old_content = node.get_content_ref().gen_content();
+                    let new_content = other_node.get_content_ref().gen_content();
+                    if old_content != new_content {
+                        edits.push(TreeEdit::ContentChanged { path: path.clone(), old: old_content, new: new_content });
+                    }
+                    // Children only in this tree are removed, children in both are compared later
+                    let mut matched_children = vec!();
+                    for child in node.get_children_ref().iter() {
+                        if let Some(child_node) = self.nodes.get(*child) {
+                            let child_val = child_node.get_content_ref().key();
+                            let mut child_path = path.clone();
+                            child_path.push(String::from(child_val));
+                            match other_node.get_child(child_val) {
+                                Some(other_child) => matched_children.push((*child, other_child, child_path)),
+                                None => edits.push(TreeEdit::Removed(child_path))
+                            }
+                        }
+                    }
+                    // Children only in the other tree are added
+                    for other_child in other_node.get_children_ref().iter() {
+                        if let Some(other_child_node) = other.nodes.get(*other_child) {
+                            if node.get_child(other_child_node.get_content_ref().key()).is_none() {
+                                other.added_edits(*other_child, &path, &mut edits);
+                            }
+                        }
+                    }
+                    pila.extend(matched_children.into_iter().rev());
+                }
+            },
+            (root, other_root) => {
+                // Different or missing roots, replace the whole tree
+                if let Some(root) = root {
+                    edits.push(TreeEdit::Removed(vec!(String::from(root.get_content_ref().key()))));
+                }
+                if other_root.is_some() {
+                    other.added_edits(0, &[], &mut edits);
+                }
+            }
+        }
+        edits
+    }
+
+    /// Apply edit operations, like the ones generated by [`Tree::diff()`].
+    /// 
+    /// Nodes are located by path, so edits don't depend on node indexes. Added nodes are created from their generated content. Removing the root node empties the tree. Edits are applied in order, if one fails, the previous ones are kept.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `edits` - Edit operations.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with nothing, or a description of the first edit that failed.
+    ///
+    pub fn apply_edits(&mut self, edits: &[TreeEdit]) -> Result<(), String> {
+        for edit in edits.iter() {
+            match edit {
+                TreeEdit::Added { path, content } => {
+                    let path: Vec<&str> = path.iter().map(|element| element.as_str()).collect();
+                    let node_index = match path.split_last() {
+                        Some((_, [])) => self.set_root(content),
+                        Some((_, parent_path)) => self.find_node(parent_path).and_then(|parent_index| self.link_node(content, parent_index)),
+                        None => None
+                    };
+                    if node_index.is_none() {
+                        return Err(format!("Can't add node {:?}", path));
+                    }
+                },
+                TreeEdit::Removed(path) => {
+                    let path: Vec<&str> = path.iter().map(|element| element.as_str()).collect();
+                    match self.find_node(&path) {
+                        Some(0) => self.nodes.clear(),
+                        Some(node_index) => {
+                            self.unlink_node(node_index);
+                        },
+                        None => return Err(format!("Can't remove node {:?}", path))
+                    }
+                },
+                TreeEdit::ContentChanged { path, new, .. } => {
+                    let path: Vec<&str> = path.iter().map(|element| element.as_str()).collect();
+                    if self.find_node(&path).and_then(|node_index| self.update_node(new, node_index)).is_none() {
+                        return Err(format!("Can't change content of node {:?}", path));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Fold the tree bottom-up, computing a value for every node from its content and the values of its children.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - Function that receives the node content and the array of values of its children, in order.
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the value of the root node.
+    ///
+    pub fn fold<R, F: FnMut(&T, Vec<R>) -> R>(&self, mut f: F) -> Option<R> {
+        let mut results = vec!();
+        for (node, node_index, _) in self.iterators().post_dfs_with_size() {
+            // In Post-Order, the values of the children are at the end of the array
+            let num_children = self.num_children(node_index).unwrap_or(0);
+            let children_results = results.split_off(results.len() - num_children);
+            results.push(f(node.get_content_ref(), children_results));
+        }
+        results.pop()
+    }
+
+    /// Get indexes of dead nodes, the ones present in the nodes array but not reachable from the root.
+    /// 
+    /// # Return
+    /// 
+    /// * Array of node indexes, in increasing order.
+    ///
+    pub fn dead_nodes(&self) -> Vec<usize> {
+        let mut reachable = vec!(false; self.nodes.len());
+        if !self.nodes.is_empty() {
+            // Mark reachable nodes using BFS
+            let mut cua = VecDeque::from(vec!(0));
+            while let Some(current_index) = cua.pop_front() {
+                // Unlinked children are skipped by nodes.get()
+                if let Some(node) = self.nodes.get(current_index) {
+                    reachable[current_index] = true;
+                    cua.extend(node.get_children_ref().iter());
+                }
+            }
+        }
+        reachable.iter().enumerate().filter(|(_, r)| !**r).map(|(i, _)| i).collect()
+    }
+
+    /// Free the content and children of dead nodes, keeping all indexes stable.
+    /// 
+    /// Dead nodes content is replaced by an empty placeholder, built with an empty string (if the content type can't be parsed from it, the content is kept), and their children are dropped. The nodes array is not shrunk, dead nodes keep their slots.
+    /// 
+    /// # Return
+    /// 
+    /// * Nothing.
+    ///
+    pub fn prune_dead(&mut self) {
+        for node_index in self.dead_nodes() {
+            let node = &mut self.nodes[node_index];
+            if let Some(placeholder) = T::new("") {
+                node.set_content(placeholder);
+            }
+            node.clear_children();
+        }
+    }
+
+    /// Check the tree integrity.
+    /// 
+    /// Verifies that every linked child points back to its parent and to its slot in the parent's children array, that its level and depth are consistent with the parent's, and that the parent's child map agrees. Useful after manual edits through [`Tree::get_mut_nodes_ref`].
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with nothing, or a description of the first inconsistency found as error.
+    ///
+    pub fn validate(&self) -> Result<(), String> {
+        if self.nodes.is_empty() {
+            return Ok(());
+        }
+        if let Some(parent_index) = self.nodes[0].get_parent_position() {
+            return Err(format!("Root node has parent {}", parent_index));
+        }
+        if self.nodes[0].get_depth() != 0 {
+            return Err(format!("Root node has depth {}", self.nodes[0].get_depth()));
+        }
+        if self.nodes[0].get_level() != self.root_level {
+            return Err(format!("Root node has level {} but the root level is {}", self.nodes[0].get_level(), self.root_level));
+        }
+        let mut visited = vec!(false; self.nodes.len());
+        let mut pila = vec!(0);
+        while let Some(node_index) = pila.pop() {
+            if visited[node_index] {
+                return Err(format!("Node {} is reachable more than once", node_index));
+            }
+            visited[node_index] = true;
+            let node = &self.nodes[node_index];
+            for (position, child_index) in node.get_children_ref().iter().enumerate() {
+                let child_index = *child_index;
+                // Unlinked child
+                if child_index == usize::MAX {
+                    continue;
+                }
+                if let Some(child) = self.nodes.get(child_index) {
+                    if child.get_parent_position() != Some(node_index) {
+                        return Err(format!("Node {} is child of {} but its parent is {:?}", child_index, node_index, child.get_parent_position()));
+                    }
+                    if child.get_parents_children_pos() != Some(position) {
+                        return Err(format!("Node {} is at position {} of its parent but points to {:?}", child_index, position, child.get_parents_children_pos()));
+                    }
+                    if child.get_level() != node.get_level() + 1 {
+                        return Err(format!("Node {} has level {} but its parent has level {}", child_index, child.get_level(), node.get_level()));
+                    }
+                    if child.get_depth() != node.get_depth() + 1 {
+                        return Err(format!("Node {} has depth {} but its parent has depth {}", child_index, child.get_depth(), node.get_depth()));
+                    }
+                    if node.get_child(child.get_content_ref().key()) != Some(child_index) {
+                        return Err(format!("Node {} is not in the child map of {}", child_index, node_index));
+                    }
+                    pila.push(child_index);
+                }
+                else {
+                    return Err(format!("Node {} has child {} out of bounds", node_index, child_index));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Walk two trees in lockstep, using BFS.
+    /// 
+    /// The iteration stops when any of the trees is exhausted. Trees can have different node content types.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other tree.
+    /// 
+    /// # Return
+    /// 
+    /// * Iterator, provides a tuple with the node and index of each tree.
+    ///
+    pub fn zip_bfs<'a, U: NodeContent>(&'a self, other: &'a Tree<U>) -> Zip<BfsIter<'a, T>, BfsIter<'a, U>> {
+        self.iterators().bfs().zip(other.iterators().bfs())
+    }
+
+    /// Make a linked node the new root, inverting the parent/child relationships along the path from the old root.
+    /// 
+    /// The former ancestors become descendants, each one linked as last child of its former child. The new root and the old root exchange their indexes, so the new root is at index 0 and the old root gets the former index of the new root, all other indexes are preserved. Levels are recalculated.
+    /// 
+    /// This process is O(n) complexity, where `n` is the number of nodes.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_index` - Index of the new root.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with nothing, or a description of the error.
+    ///
+    pub fn reroot(&mut self, node_index: usize) -> Result<(), String> {
+        if !self.is_linked(node_index) {
+            return Err(format!("Node {} is not linked", node_index));
+        }
+        if node_index == 0 {
+            return Ok(());
+        }
+        let chain = self.ancestors_of(node_index).unwrap_or_default();
+        // Check the new relations before changing anything
+        if let (Some(max_children), Some(num_children)) = (self.max_children, self.num_children(node_index)) {
+            if num_children >= max_children {
+                return Err(format!("Node {} can't have more children", node_index));
+            }
+        }
+        for (i, pair) in chain.windows(2).enumerate() {
+            let (parent_index, child_index) = (pair[0], pair[1]);
+            let parent_val = self.nodes[parent_index].get_content_ref().key();
+            if let Some(sibling_index) = self.nodes[child_index].get_child(parent_val) {
+                // The only child that can share the name is the one that will be unlinked
+                if chain.get(i + 2) != Some(&sibling_index) {
+                    return Err(format!("Node {} already has a child named \"{}\"", child_index, parent_val));
+                }
+            }
+        }
+        // Unlink all nodes in the path
+        for child_index in chain[1..].iter() {
+            self.unlink_node(*child_index);
+        }
+        // Link them again, in inverse order
+        for pair in chain.windows(2) {
+            let (parent_index, child_index) = (pair[0], pair[1]);
+            let parents_children_pos = self.nodes[child_index].get_num_children();
+            let parent_val = String::from(self.nodes[parent_index].get_content_ref().key());
+            self.nodes[child_index].add_child(parent_val, parent_index);
+            self.nodes[parent_index].set_parent_position(child_index);
+            self.nodes[parent_index].set_parents_children_pos(parents_children_pos);
+        }
+        self.nodes[node_index].clear_parent();
+        // Move the new root to index 0
+        self.nodes.swap(0, node_index);
+        for node in self.nodes.iter_mut() {
+            node.remap_indexes(|index| if index == 0 { node_index } else if index == node_index { 0 } else { index });
+        }
+        // Recalculate levels
+        let mut pila = vec!((0, self.root_level));
+        while let Some((current_index, level)) = pila.pop() {
+            if let Some(node) = self.nodes.get_mut(current_index) {
+                node.set_level(level);
+                node.set_depth(level - self.root_level);
+                pila.extend(node.get_children_ref().iter().map(|child| (*child, level + 1)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Exchange the content of two nodes, keeping their positions and children. Both nodes must be linked.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node_a` - First node index.
+    /// * `node_b` - Second node index.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with nothing, or a description of the error. On error the tree is not modified.
+    ///
+    pub fn swap_nodes_content(&mut self, node_a: usize, node_b: usize) -> Result<(), String> {
+        if node_a >= self.nodes.len() || node_b >= self.nodes.len() {
+            return Err(format!("Node {} or {} doesn't exist", node_a, node_b));
+        }
+        if node_a == node_b {
+            return Ok(());
+        }
+        // A stale parent of an unlinked node would desync a live child map
+        for node_index in [node_a, node_b].iter() {
+            if !self.is_linked(*node_index) {
+                return Err(format!("Node {} is not linked", node_index));
+            }
+        }
+        let val_a = String::from(self.nodes[node_a].get_content_ref().key());
+        let val_b = String::from(self.nodes[node_b].get_content_ref().key());
+        let parent_a = self.nodes[node_a].get_parent_position();
+        let parent_b = self.nodes[node_b].get_parent_position();
+        if val_a != val_b {
+            if parent_a.is_some() && parent_a == parent_b {
+                // Siblings, just exchange their indexes
+                if let Some(parent) = parent_a {
+                    if self.nodes[parent].swap_children(&val_a, &val_b).is_none() {
+                        return Err(format!("Node {} child map is out of sync", parent));
+                    }
+                }
+            }
+            else {
+                // Check collisions before changing anything
+                for (parent, new_val) in [(parent_a, &val_b), (parent_b, &val_a)].iter() {
+                    if let Some(parent) = parent {
+                        if self.nodes[*parent].get_child(new_val).is_some() {
+                            return Err(format!("Node {} already has a child named \"{}\"", parent, new_val));
+                        }
+                    }
+                }
+                if let Some(parent) = parent_a {
+                    self.nodes[parent].update_child(&val_a, &val_b);
+                }
+                if let Some(parent) = parent_b {
+                    self.nodes[parent].update_child(&val_b, &val_a);
+                }
+            }
+        }
+        let (low, high) = if node_a < node_b { (node_a, node_b) } else { (node_b, node_a) };
+        let (left, right) = self.nodes.split_at_mut(high);
+        core::mem::swap(left[low].get_mut_content_ref(), right[0].get_mut_content_ref());
+        Ok(())
+    }
+
+    /// Transform the content of all nodes, without changing the tree structure.
+    /// 
+    /// If the function changes the key of a node, the child map of its parent is rebuilt. The function must keep the keys of sibling nodes distinct.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - Function that receives the mutable content of each node.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with nothing, or a description of the error if two siblings end up with the same key. On error the contents are already transformed and the tree is inconsistent, see [`Tree::validate()`].
+    ///
+    pub fn map_in_place<F: FnMut(&mut T)>(&mut self, mut f: F) -> Result<(), String> {
+        let mut changed_parents = vec!(false; self.nodes.len());
+        for node in self.nodes.iter_mut() {
+            let old_val = String::from(node.get_content_ref().key());
+            f(node.get_mut_content_ref());
+            if node.get_content_ref().key() != old_val {
+                if let Some(parent_position) = node.get_parent_position() {
+                    changed_parents[parent_position] = true;
+                }
+            }
+        }
+        let mut result = Ok(());
+        for (parent_position, _) in changed_parents.iter().enumerate().filter(|(_, changed)| **changed) {
+            let children = self.children_indexes(parent_position).unwrap_or_default();
+            let entries: Vec<(String, usize)> = children.into_iter().map(|child| (String::from(self.nodes[child].get_content_ref().key()), child)).collect();
+            // Entries with the same key would be merged in the child map
+            if result.is_ok() {
+                let mut keys = Map::new();
+                if let Some((key, _)) = entries.iter().find(|(key, _)| keys.insert(key, ()).is_some()) {
+                    result = Err(format!("Node {} already has a child named \"{}\"", parent_position, key));
+                }
+            }
+            self.nodes[parent_position].rebuild_child_map(entries);
+        }
+        result
+    }
+
+    /// Get a map of paths to node indexes, to resolve many paths efficiently.
+    /// 
+    /// This process is O(n) complexity, where `n` is the number of nodes.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `sep` - Separator used to join the node keys of a path.
+    /// 
+    /// # Return
+    /// 
+    /// * Map with the path of every linked node, starting from root, and its index.
+    ///
+    pub fn path_index(&self, sep: char) -> Map<String, usize> {
+        let mut paths = Map::new();
+        if self.nodes.is_empty() {
+            return paths;
+        }
+        let mut pila = vec!((0, String::from(self.nodes[0].get_content_ref().key())));
+        while let Some((node_index, node_path)) = pila.pop() {
+            for child in self.nodes[node_index].get_children_ref().iter() {
+                if let Some(child_node) = self.nodes.get(*child) {
+                    pila.push((*child, format!("{}{}{}", node_path, sep, child_node.get_content_ref().key())));
+                }
+            }
+            paths.insert(node_path, node_index);
+        }
+        paths
+    }
+
+    /// Convert the tree to another node content type, keeping the structure and indexes.
+    /// 
+    /// Every new node content is parsed from the generated content of the current one, see [`NodeContent::gen_content()`].
+    /// 
+    /// # Return
+    /// 
+    /// * An [`Option`] with the new tree, or None if any content parsing fails.
+    ///
+    pub fn map<U: NodeContent>(&self) -> Option<Tree<U>> {
+        let mut nodes = Vec::with_capacity(self.nodes.len());
+        for node in self.nodes.iter() {
+            nodes.push(node.map::<U>()?);
+        }
+        let mut tree = Tree {
+            nodes,
+            max_children: self.max_children,
+            root_level: self.root_level
+        };
+        // New content type can have different keys
+        for node_index in 0..tree.nodes.len() {
+            let children = tree.children_indexes(node_index).unwrap_or_default();
+            let entries: Vec<(String, usize)> = children.into_iter().filter(|child| *child < tree.nodes.len()).map(|child| (String::from(tree.nodes[child].get_content_ref().key()), child)).collect();
+            tree.nodes[node_index].rebuild_child_map(entries);
+        }
+        Some(tree)
+    }
+
+    /// Get an owned snapshot of all linked nodes, in BFS order.
+    /// 
+    /// # Return
+    /// 
+    /// * Array of tuples with the generated content, the node index and the parent index.
+    ///
+    pub fn bfs_collect(&self) -> Vec<(String, usize, Option<usize>)> {
+        self.iterators().by_level().flat_map(|(_, nodes)| nodes).map(|(node, node_index)| (node.get_content_ref().gen_content(), node_index, node.get_parent_position())).collect()
+    }
+
+    /// Get the adjacency list of the tree, the children of every linked node.
+    /// 
+    /// # Return
+    /// 
+    /// * Array of tuples with the node index and the array of linked children indexes, in Pre-Order DFS. Unlinked nodes are omitted.
+    ///
+    pub fn to_adjacency_list(&self) -> Vec<(usize, Vec<usize>)> {
+        self.subtree_indexes(0).into_iter().map(|node_index| (node_index, self.children_indexes(node_index).unwrap_or_default())).collect()
+    }
+
+    /// Create a tree from a list of edges, the inverse of [`Tree::to_adjacency_list()`].
+    /// 
+    /// There must be exactly one node without parent, the root node, and no cycles. Nodes are linked in BFS order, so indexes in the new tree can differ from the list indexes.
+    /// 
+    /// Any index without a parent is taken as a root, so the inverse only holds for trees without unlinked nodes. Otherwise the indexes must be compacted first, for example using the position of each node in the adjacency list.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `edges` - Array of tuples with the parent and child indexes. Children of a node are linked in the order of the array.
+    /// * `contents` - Content of each node, by index.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with the tree, or a description of the error.
+    ///
+    pub fn from_adjacency_list(edges: &[(usize, usize)], contents: &[&str]) -> Result<Self, String> {
+        let mut tree = Self::new();
+        if contents.is_empty() && edges.is_empty() {
+            return Ok(tree);
+        }
+        let mut children = vec!(vec!(); contents.len());
+        let mut has_parent = vec!(false; contents.len());
+        for (parent, child) in edges.iter() {
+            if *parent >= contents.len() || *child >= contents.len() {
+                return Err(format!("Edge ({}, {}) out of bounds", parent, child));
+            }
+            if has_parent[*child] {
+                return Err(format!("Node {} has more than one parent", child));
+            }
+            has_parent[*child] = true;
+            children[*parent].push(*child);
+        }
+        let mut roots = (0..contents.len()).filter(|node_index| !has_parent[*node_index]);
+        let root = match (roots.next(), roots.next()) {
+            (Some(root), None) => root,
+            (None, _) => return Err(String::from("No root node")),
+            (Some(_), Some(_)) => return Err(String::from("More than one root node"))
+        };
+        if tree.set_root(contents[root]).is_none() {
+            return Err(format!("Invalid node content \"{}\"", contents[root]));
+        }
+        let mut cua = VecDeque::from(vec!((root, 0)));
+        while let Some((list_index, node_index)) = cua.pop_front() {
+            for child in children[list_index].iter() {
+                if let Some(child_index) = tree.link_node(contents[*child], node_index) {
+                    cua.push_back((*child, child_index));
+                }
+                else {
+                    return Err(format!("Invalid node content \"{}\"", contents[*child]));
+                }
+            }
+        }
+        // With a single root and a single parent per node, unreachable nodes are in a cycle
+        if tree.nodes.len() < contents.len() {
+            return Err(String::from("Edges contain a cycle"));
+        }
+        Ok(tree)
+    }
+
+    /// Serialize the tree to an indented string, one node per line.
+    /// 
+    /// Nodes are written in Pre-Order DFS, indented with 2 spaces per level, using [`NodeContent::gen_content()`], so custom node types keep their own format. Content that is empty or starts with a space or a backslash is escaped with a backslash. Unlinked nodes are skipped. See [`Tree::parse_string()`] for the inverse.
+    /// 
+    /// # Return
+    /// 
+    /// * Serialized tree.
+    ///
+    pub fn gen_string(&self) -> String {
+        let mut text = String::new();
+        for node_index in self.subtree_indexes(0) {
+            let node = &self.nodes[node_index];
+            text.push_str(&" ".repeat(2 * node.get_level().saturating_sub(self.root_level)));
+            let node_content = node.get_content_ref().gen_content();
+            // Leading spaces would be taken as indentation
+            if node_content.is_empty() || node_content.starts_with(' ') || node_content.starts_with('\\') {
+                text.push('\\');
+            }
+            text.push_str(&node_content);
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Get iterators interface, starting at a certain node.
+    /// 
+    /// If the node doesn't exist or is unlinked, iterators start at root. Use [`Tree::try_iterators_at()`] to detect it.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `initial_node` - Index of the node where traversals start.
+    /// 
+    /// # Return
+    /// 
+    /// * Iterators interface.
+    ///
+    pub fn iterators_at(&self, initial_node: usize) -> IterInterface<'_, T> {
+        self.try_iterators_at(initial_node).unwrap_or_else(|_| IterInterface::new(self))
+    }
+
+    /// Get iterators interface, starting at a certain node, that must be linked.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `initial_node` - Index of the node where traversals start.
+    /// 
+    /// # Return
+    /// 
+    /// * A [`Result`] with the iterators interface, or a description of the error.
+    ///
+    pub fn try_iterators_at(&self, initial_node: usize) -> Result<IterInterface<'_, T>, String> {
+        if initial_node >= self.nodes.len() {
+            return Err(format!("Node {} doesn't exist", initial_node));
+        }
+        if !self.is_linked(initial_node) {
+            return Err(format!("Node {} is not linked", initial_node));
+        }
+        Ok(IterInterface::new_at(self, initial_node))
+    }
+
+    /// Get iterators interface.
+    /// 
+    /// # Return
+    /// 
+    /// * Iterators interface.
+    ///
+    pub fn iterators(&self) -> IterInterface<'_, T> {
+        IterInterface::new(self)
+    }
+
+    /// Get reference to nodes array.
+    /// 
+    /// # Return
+    /// 
+    /// * Array reference.
+    ///
+    pub fn get_nodes_ref(&self) -> &[Node<T>] {
+        &self.nodes
+    }
+
+    /// Get mutable reference to nodes array.
+    /// 
+    /// **WARNING**: this is intended for advanced editing. Modifying the content or the children of a node directly can desync the parent's child map and break the tree structure, use it with care.
+    /// 
+    /// # Return
+    /// 
+    /// * Mutable array reference.
+    ///
+    pub fn get_mut_nodes_ref(&mut self) -> &mut [Node<T>] {
+        &mut self.nodes
+    }
+
+    /// Get size of nodes array.
+    /// 
+    /// # Return
+    /// 
+    /// * Size.
+    ///
+    pub fn get_nodes_len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    // Get indexes of all nodes in a subtree, in Pre-Order DFS.
+    fn subtree_indexes(&self, node_index: usize) -> Vec<usize> {
+        let mut node_indexes = vec!();
+        let mut pila = vec!(node_index);
+        while let Some(current_index) = pila.pop() {
+            if let Some(node) = self.nodes.get(current_index) {
+                node_indexes.push(current_index);
+                // Put in the stack all children of current node, unlinked ones are skipped by nodes.get()
+                pila.extend(node.get_children_ref().iter().rev());
+            }
+        }
+        node_indexes
+    }
+
+    // Find child by exact key, or using NodeContent::matches if not found.
+    fn find_child(&self, node_index: usize, path_element: &str) -> Option<usize> {
+        let node = &self.nodes[node_index];
+        if let Some(child_index) = node.get_child(path_element) {
+            return Some(child_index);
+        }
+        node.get_children_ref().iter().copied().find(|child| {
+            match self.nodes.get(*child) {
+                Some(child_node) => child_node.get_content_ref().matches(path_element),
+                None => false
+            }
+        })
+    }
+
+    // Generate Added edits for all nodes in a subtree, in Pre-Order DFS.
+    fn added_edits(&self, node_index: usize, parent_path: &[String], edits: &mut Vec<TreeEdit>) {
+        let mut pila = vec!((node_index, parent_path.to_vec()));
+        while let Some((current_index, mut path)) = pila.pop() {
+            if let Some(node) = self.nodes.get(current_index) {
+                path.push(String::from(node.get_content_ref().key()));
+                for child in node.get_children_ref().iter().rev() {
+                    pila.push((*child, path.clone()));
+                }
+                edits.push(TreeEdit::Added { path, content: node.get_content_ref().gen_content() });
+            }
+        }
+    }
+
+    // Check that linking under target_index doesn't create a cycle, that is, target_index is not node_index or one of its descendants.
+    fn assert_no_cycle(&self, node_index: usize, target_index: usize) -> Result<(), String> {
+        if target_index == node_index || self.is_ancestor(node_index, target_index) {
+            return Err(format!("Node {} can't be linked to its own descendant {}", node_index, target_index));
+        }
+        Ok(())
+    }
+
+    // Get the children array of the parent and the position of a node in it, if the node is linked to its parent.
+    fn sibling_slots(&self, node_index: usize) -> Option<(&[usize], usize)> {
+        let node = self.nodes.get(node_index)?;
+        let siblings = self.nodes.get(node.get_parent_position()?)?.get_children_ref();
+        let position = node.get_parents_children_pos()?;
+        if siblings.get(position) == Some(&node_index) {
+            return Some((siblings, position));
+        }
+        None
+    }
+
+    // TODO
+    /*
+    /// Obtain a copy of the current tree without unlinked nodes and updating node indexes.
+    /// 
+    /// Node indexes of the old tree are no longer valid in the new tree returned by this function.
+    /// 
+    /// # Return
+    /// 
+    /// * Regenerated tree.
+    ///
+    pub fn regenerate(&self) -> Self {
+        Tree::new()
+    }
+    */
+}
+
+/// Create an empty tree, like [`Tree::new()`].
+impl<T: NodeContent> Default for Tree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Print the tree as indented text, one node per line in Pre-Order DFS, indented two spaces per level.
+impl<T: NodeContent> fmt::Display for Tree<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for node_index in self.subtree_indexes(0) {
+            let node = &self.nodes[node_index];
+            writeln!(f, "{:indent$}{}", "", node, indent = 2 * node.get_level().saturating_sub(self.root_level))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: NodeContent + Sync> Tree<T> {
+    /// Run a function for every linked node in parallel.
+    /// 
+    /// Nodes are collected in BFS order and then processed using [rayon](https://docs.rs/rayon), so there is no guarantee about the order of execution. Requires the `rayon` feature.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - Function that receives the node and its index.
+    /// 
+    /// # Return
+    /// 
+    /// * Nothing.
+    ///
+    pub fn par_for_each_bfs<F: Fn(&Node<T>, usize) + Sync>(&self, f: F) {
+        use rayon::prelude::*;
+        let nodes: Vec<(&Node<T>, usize)> = self.iterators().by_level().flat_map(|(_, nodes)| nodes).collect();
+        nodes.par_iter().for_each(|(node, node_index)| f(node, *node_index));
     }
-    */
 }
\ No newline at end of file